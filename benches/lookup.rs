@@ -0,0 +1,70 @@
+//! Compares the allocation-free lookups against the previous
+//! `to_uppercase()` / `format!("{:03}")` + `phf` implementation.
+//!
+//! Run with `cargo bench --bench lookup`.
+
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use rust_iso4217::{CurrencyCode, ALL_CODE, ALL_MAP, ALL_NUMERIC, NUMERIC_MAP};
+
+fn phf_from_code(alpha3: &str) -> Option<CurrencyCode> {
+    let up = alpha3.to_uppercase();
+    ALL_MAP.get(&up).cloned()
+}
+
+fn phf_from_numeric(numeric: i32) -> Option<CurrencyCode> {
+    let k = format!("{:03}", numeric);
+    NUMERIC_MAP.get(&k).cloned()
+}
+
+fn bench_from_code(c: &mut Criterion) {
+    let mut group = c.benchmark_group("from_code");
+    group.bench_function("phf+String", |b| {
+        b.iter(|| {
+            for code in ALL_CODE {
+                black_box(phf_from_code(black_box(code)));
+            }
+        })
+    });
+    group.bench_function("ascii", |b| {
+        b.iter(|| {
+            for code in ALL_CODE {
+                black_box(rust_iso4217::from_code(black_box(code)));
+            }
+        })
+    });
+    group.finish();
+}
+
+fn bench_from_numeric(c: &mut Criterion) {
+    let mut group = c.benchmark_group("from_numeric");
+    group.bench_function("phf+format", |b| {
+        b.iter(|| {
+            for numeric in ALL_NUMERIC {
+                black_box(phf_from_numeric(black_box(*numeric)));
+            }
+        })
+    });
+    group.bench_function("table", |b| {
+        b.iter(|| {
+            for numeric in ALL_NUMERIC {
+                black_box(rust_iso4217::from_numeric(black_box(*numeric)));
+            }
+        })
+    });
+    group.finish();
+}
+
+fn bench_parse(c: &mut Criterion) {
+    c.bench_function("parse::<Currency>", |b| {
+        b.iter(|| {
+            for code in ALL_CODE {
+                black_box(black_box(code).parse::<rust_iso4217::Currency>().ok());
+            }
+        })
+    });
+}
+
+criterion_group!(benches, bench_from_code, bench_from_numeric, bench_parse);
+criterion_main!(benches);
@@ -92,6 +92,7 @@ for row in table_funds.index:
 list_three_xls = pd.ExcelFile(r"list-three.xls")
 table_historic = list_three_xls.parse(list_three_xls.sheet_names[0])
 
+withdrawals = []
 for row in table_historic.index:
     code = {}
     for i, k in enumerate(active_keys):
@@ -100,6 +101,10 @@ for row in table_historic.index:
         continue
     if code["entity"] == "ENTITY":
         continue
+    # The fifth column of list three is the withdrawal date.
+    withdrawals.append({"code": code["code"],
+                        "entity": " ".join(str(code["entity"]).split()),
+                        "date": str(code["unit"]).strip()})
 
     code["type"] = "historic"
     code["unit"] = "-1"
@@ -170,13 +175,77 @@ ensure_country_currency("FLK", "FKP")
 currency_country["TRY"] = [{"iso3166_code": "TUR"}]
 ensure_country_currency("TUR", "TRY")
 
-prefix = """use phf::{phf_map, Map};
+prefix = r"""#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(all(target_arch = "wasm32", not(feature = "std")))]
+compile_error!("the wasm bindings require the `std` feature");
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use phf::{phf_map, Map};
 #[cfg(target_arch = "wasm32")]
 use wasm_bindgen::prelude::*;
 
 #[cfg(target_arch = "wasm32")]
 use js_sys::Array;
 
+mod bag;
+mod code_type;
+mod collections;
+mod country;
+#[cfg(feature = "alloc")]
+mod conversion;
+mod currency;
+mod date;
+mod decimal;
+mod error;
+mod euro;
+mod history;
+#[cfg(feature = "alloc")]
+mod loader;
+mod lookup;
+mod macros;
+mod minor_unit;
+mod money;
+mod parse;
+mod provider;
+mod rate;
+#[cfg(feature = "serde")]
+pub mod serde;
+#[cfg(feature = "alloc")]
+mod series;
+#[cfg(feature = "alloc")]
+mod triangulate;
+pub use bag::MoneyBag;
+pub use code_type::CodeType;
+pub use collections::{CurrencyMap, CurrencySet, CurrencySetIter};
+pub use country::from_country_on;
+#[cfg(feature = "alloc")]
+pub use conversion::{convert_with, Conversion};
+pub use date::Date;
+pub use decimal::{Decimal, Rounded, RoundingMode};
+pub use error::{
+    CountryError, DateError, LoadError, MinorUnitError, MoneyError, ParseCurrencyError, RateError,
+};
+pub use euro::euro_convert;
+pub use history::{resolve_current, restate, Replacement, Restated, Withdrawal};
+#[cfg(feature = "alloc")]
+pub use loader::{LoadIssue, RateFormat};
+#[doc(hidden)]
+pub use macros::__private;
+pub use money::Money;
+#[cfg(feature = "alloc")]
+pub use provider::OfflineRates;
+pub use provider::RateProvider;
+pub use rate::{convert, ExchangeRate};
+#[cfg(feature = "alloc")]
+pub use series::RateSeries;
+#[cfg(feature = "alloc")]
+pub use triangulate::{Triangulated, Triangulator};
+
 #[cfg(test)]
 mod tests {
     #[test]
@@ -197,6 +266,28 @@ mod tests {
         print!("test_from_code result {:?}", l)
     }
 
+    #[test]
+    fn test_from_country() {
+        assert_eq!(crate::from_country_slice("hrv").len(), 1);
+        assert_eq!(crate::from_country_slice("hrv")[0].code, "EUR");
+        assert!(crate::from_country_slice("ATA").is_empty());
+        assert!(crate::from_country_slice("").is_empty());
+        #[cfg(feature = "alloc")]
+        assert_eq!(crate::from_country("hrv"), vec![crate::EUR]);
+        assert_eq!(
+            crate::try_from_country("SGS"),
+            Err(crate::CountryError::NoCurrency)
+        );
+        assert_eq!(
+            crate::try_from_country("XYZ"),
+            Err(crate::CountryError::UnknownCountry)
+        );
+        assert_eq!(
+            crate::try_from_country("U$A"),
+            Err(crate::CountryError::Malformed)
+        );
+    }
+
     #[test]
     fn test_all() {
         println!("{:?}", crate::ALL);
@@ -217,7 +308,7 @@ pub struct CurrencyCode {
     //Minor unit
     unit: i32,
     //type
-    code_type: &'static str,
+    code_type: CodeType,
     //countries
     countries: &'static [&'static str],
 }
@@ -234,7 +325,7 @@ pub struct CurrencyCode {
     //Minor unit
     pub unit: i32,
     //type
-    pub code_type: &'static str,
+    pub code_type: CodeType,
     pub countries: &'static [&'static str],
 }
 
@@ -260,8 +351,8 @@ impl CurrencyCode {
         self.unit
     }
     #[wasm_bindgen(getter)]
-    pub fn code_type(&self) -> String {
-        self.code_type.into()
+    pub fn code_type(&self) -> CodeType {
+        self.code_type
     }
     #[wasm_bindgen(getter)]
     pub fn countries(&self) -> Array {
@@ -282,8 +373,7 @@ impl CurrencyCode {
 /// ```
 #[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
 pub fn from_code(alpha3: &str) -> Option<CurrencyCode> {
-    let up = alpha3.to_uppercase();
-    ALL_MAP.get(&up).cloned()
+    lookup::alpha3(alpha3.as_bytes()).map(Currency::into)
 }
 /// Returns the CurrencyCode with the given numeric , if exists.
 // #Sample
@@ -293,8 +383,8 @@ pub fn from_code(alpha3: &str) -> Option<CurrencyCode> {
 /// ```
 #[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
 pub fn from_numeric(numeric: i32) -> Option<CurrencyCode> {
-    let k = format!("{:03}", numeric);
-    NUMERIC_MAP.get(&k).cloned()
+    let numeric = u16::try_from(numeric).ok()?;
+    lookup::numeric(numeric).map(Currency::into)
 }
 
 /// Returns the CurrencyCode with the given numeric 3 length str, if exists.
@@ -305,44 +395,84 @@ pub fn from_numeric(numeric: i32) -> Option<CurrencyCode> {
 /// ```
 #[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
 pub fn from_numeric_str(numeric: &str) -> Option<CurrencyCode> {
-    NUMERIC_MAP.get(numeric).cloned()
+    let numeric = lookup::numeric_digits(numeric.as_bytes())?;
+    lookup::numeric(numeric).map(Currency::into)
 }
 
-/// Returns the CurrencyCode with the given numeric 3 length str, if exists.
+/// Returns the CurrencyCode list used by the given ISO 3166-1 alpha-3 country, or an empty list.
 // #Sample
 /// ```
 /// let currencies = rust_iso4217::from_country("CHN");
 /// assert_eq!(currencies.len() > 0,true)
 /// ```
-#[cfg(not(target_arch = "wasm32"))]
+#[cfg(all(not(target_arch = "wasm32"), feature = "alloc"))]
 pub fn from_country(country: &str) -> Vec<CurrencyCode> {
-    let mut vector: Vec<CurrencyCode> = Vec::new();
-    let currencies = COUNTRY_MAP.get(country);
-    match currencies {
-        Some(cs) => {
-            for i in 0..cs.len() {
-                vector.push(cs[i].clone())
-            }
-        }
-        None => todo!(),
-    }
-    vector
+    try_from_country(country)
+        .map(|cs| cs.iter().map(|c| **c).collect())
+        .unwrap_or_default()
+}
+
+/// Returns the CurrencyCode table slice for the given ISO 3166-1 alpha-3 country, or an empty
+/// slice. Allocation free, so available without the `alloc` feature.
+// #Sample
+/// ```
+/// let currencies = rust_iso4217::from_country_slice("CHN");
+/// assert_eq!("CNY", currencies[0].code);
+/// assert!(rust_iso4217::from_country_slice("ZZZ").is_empty());
+/// ```
+#[cfg(not(target_arch = "wasm32"))]
+pub fn from_country_slice(country: &str) -> &'static [&'static CurrencyCode] {
+    try_from_country(country).unwrap_or(&[])
 }
 
 #[cfg(target_arch = "wasm32")]
 #[wasm_bindgen]
 pub fn from_country(country: &str) -> Array {
-    let mut vector: Vec<CurrencyCode> = Vec::new();
-    let currencies = COUNTRY_MAP.get(country);
-    match currencies {
-        Some(cs) => {
-            for i in 0..cs.len() {
-                vector.push(cs[i].clone())
-            }
-        }
-        None => todo!(),
+    match lookup_country(country) {
+        Ok(cs) => cs.iter().map(|c| JsValue::from(**c)).collect(),
+        Err(_) => Array::new(),
+    }
+}
+
+/// Returns the CurrencyCode list used by the given ISO 3166-1 alpha-3 country, case-insensitively.
+// #Sample
+/// ```
+/// use rust_iso4217::CountryError;
+///
+/// let currencies = rust_iso4217::try_from_country("che").unwrap();
+/// assert_eq!("CHF", currencies[0].code);
+/// assert_eq!(Err(CountryError::NoCurrency), rust_iso4217::try_from_country("ATA"));
+/// assert_eq!(Err(CountryError::UnknownCountry), rust_iso4217::try_from_country("ZZZ"));
+/// assert_eq!(Err(CountryError::Malformed), rust_iso4217::try_from_country("CH"));
+/// ```
+#[cfg(not(target_arch = "wasm32"))]
+pub fn try_from_country(country: &str) -> Result<&'static [&'static CurrencyCode], CountryError> {
+    lookup_country(country)
+}
+
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen]
+pub fn try_from_country(country: &str) -> Result<Array, JsError> {
+    let currencies = lookup_country(country)?;
+    Ok(currencies.iter().map(|c| JsValue::from(**c)).collect())
+}
+
+fn lookup_country(country: &str) -> Result<&'static [&'static CurrencyCode], CountryError> {
+    let bytes = country.as_bytes();
+    if bytes.len() != 3 || !bytes.iter().all(u8::is_ascii_alphabetic) {
+        return Err(CountryError::Malformed);
+    }
+    let mut up = [0u8; 3];
+    for (u, b) in up.iter_mut().zip(bytes) {
+        *u = b.to_ascii_uppercase();
+    }
+    // ASCII letters only, so this cannot fail.
+    let key = core::str::from_utf8(&up).map_err(|_| CountryError::Malformed)?;
+    match COUNTRY_MAP.get(key) {
+        Some(cs) => Ok(cs),
+        None if NO_CURRENCY_COUNTRY.contains(&key) => Err(CountryError::NoCurrency),
+        None => Err(CountryError::UnknownCountry),
     }
-    vector.into_iter().map(JsValue::from).collect()
 }
 
 #[cfg(target_arch = "wasm32")]
@@ -350,7 +480,7 @@ pub fn from_country(country: &str) -> Array {
 pub fn all_active_code() -> Array {
     let mut vector: Vec<&str> = Vec::new();
     for i in 0..ALL_ACTIVE_CODE.len() {
-        vector.push(ALL_ACTIVE_CODE[i].clone())
+        vector.push(ALL_ACTIVE_CODE[i])
     }
     vector.into_iter().map(JsValue::from).collect()
 }
@@ -360,7 +490,7 @@ pub fn all_active_code() -> Array {
 pub fn all_funds_code() -> Array {
     let mut vector: Vec<&str> = Vec::new();
     for i in 0..ALL_FUNDS_CODE.len() {
-        vector.push(ALL_FUNDS_CODE[i].clone())
+        vector.push(ALL_FUNDS_CODE[i])
     }
     vector.into_iter().map(JsValue::from).collect()
 }
@@ -370,7 +500,7 @@ pub fn all_funds_code() -> Array {
 pub fn all_historic_code() -> Array {
     let mut vector: Vec<&str> = Vec::new();
     for i in 0..ALL_HISTORIC_CODE.len() {
-        vector.push(ALL_HISTORIC_CODE[i].clone())
+        vector.push(ALL_HISTORIC_CODE[i])
     }
     vector.into_iter().map(JsValue::from).collect()
 }
@@ -398,11 +528,11 @@ pub const %s: CurrencyCode = CurrencyCode {
     code: "%s",
     numeric: %s,
     unit: %s,
-    code_type: "%s",
+    code_type: CodeType::%s,
     countries: &[%s],
 
 };
-""" % (code["code"].upper(), code["currency"], code["code"].upper(), code["numeric"], code["unit"], code["type"], countries))
+""" % (code["code"].upper(), code["currency"], code["code"].upper(), int(code["numeric"]), code["unit"], code["type"].capitalize(), countries))
 
 
 print("""
@@ -453,6 +583,17 @@ print("""
 };
 """)
 
+print("""
+///ISO 3166-1 alpha-3 codes of countries without a currency
+pub const NO_CURRENCY_COUNTRY: & [ & str] = &[
+    """)
+for x in country_currency:
+    if x != "" and len(country_currency[x]) == 0:
+        print("\"%s\"," % (x))
+print("""
+];
+""")
+
 
 print("""
 ///ALL names
@@ -515,7 +656,7 @@ pub const ALL_NUMERIC: & [i32] = &[
 for x in codes:
     if x["code"] in exclude_map_keys or x["numeric"] == "-1":
         continue
-    print("%s," % (x["numeric"]))
+    print("%s," % (int(x["numeric"])))
 print("""
 ];
 """)
@@ -541,3 +682,85 @@ for x in codes:
 print("""
 ];
 """)
+
+print("""
+///Every ISO 4217 code as an enum variant, in `ALL_CODES` order
+#[allow(clippy::upper_case_acronyms)]
+#[non_exhaustive]
+#[repr(u16)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Currency {
+""")
+for x in codes:
+    print("    ///%s" % (x["currency"].replace("\\\"", "\"")))
+    print("    %s," % (x["code"].upper()))
+print("""
+}
+""")
+
+print("""
+///ALL the Currency variants
+pub const ALL_CURRENCIES: & [Currency] = &[
+""")
+for x in codes:
+    print("Currency::%s," % (x["code"].upper()))
+print("""
+];
+""")
+
+print("""
+impl Currency {
+    ///Returns the variant for an upper case alpha3 code, if exists
+    pub const fn from_alpha3(code: &[u8; 3]) -> Option<Currency> {
+        match code {
+""")
+for x in codes:
+    print("            b\"%s\" => Some(Currency::%s)," % (x["code"].upper(), x["code"].upper()))
+print("""            _ => None,
+        }
+    }
+}
+""")
+
+print("""
+impl Currency {
+    ///Returns the variant for a numeric code, if exists
+    pub const fn from_numeric(numeric: u16) -> Option<Currency> {
+        match numeric {
+""")
+for x in codes:
+    if x["code"] in exclude_map_keys or x["numeric"] == "-1":
+        continue
+    print("            %s => Some(Currency::%s)," % (int(x["numeric"]), x["code"].upper()))
+print("""            _ => None,
+        }
+    }
+}
+""")
+
+
+def parse_withdrawal(date):
+    """First and last month of a withdrawal date such as `2002-03`,
+    `1989 to 1990`, `1989-1990` or `1990-07 to 1990-09`."""
+    m = re.fullmatch(r"(\d{4})(?:-(\d{2}))?(?:\s*(?:to|-)\s*(\d{4})(?:-(\d{2}))?)?", date)
+    if m is None:
+        raise ValueError("unexpected withdrawal date %r" % date)
+    first_year, first_month, last_year, last_month = m.groups()
+    if last_year is None:
+        last_year, last_month = first_year, first_month
+    return ((int(first_year), int(first_month or 1)),
+            (int(last_year), int(last_month or 12)))
+
+
+code_index = {x["code"]: i for i, x in enumerate(codes)}
+print("""
+///Withdrawals listed in ISO 4217 list three, by currency in `ALL_CODES` order
+pub const WITHDRAWALS: & [Withdrawal] = &[
+""")
+for x in sorted(withdrawals, key=lambda x: (code_index[x["code"]], parse_withdrawal(x["date"]))):
+    first, last = parse_withdrawal(x["date"])
+    print("    Withdrawal::new(Currency::%s, \"%s\", (%d, %d), (%d, %d))," % (
+        x["code"].upper(), x["entity"], first[0], first[1], last[0], last[1]))
+print("""
+];
+""")
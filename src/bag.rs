@@ -0,0 +1,234 @@
+use crate::lookup::ALPHA_ORDER;
+use crate::{
+    Currency, CurrencyCode, CurrencyMap, Decimal, Money, MoneyError, Rounded, RoundingMode,
+};
+
+/// Running balances in several currencies, without converting them.
+///
+/// Balances are kept per currency in a [`CurrencyMap`] and iterate in alpha
+/// code order. A failed operation leaves the bag unchanged.
+/// #Sample
+/// ```
+/// use rust_iso4217::{Money, MoneyBag};
+///
+/// let mut bag = MoneyBag::new();
+/// bag.add(Money::from_decimal_str("10.50", rust_iso4217::EUR).unwrap()).unwrap();
+/// bag.add(Money::from_major(1000, rust_iso4217::JPY).unwrap()).unwrap();
+/// bag.sub(Money::from_decimal_str("0.50", rust_iso4217::EUR).unwrap()).unwrap();
+/// assert_eq!(1000, bag.get(rust_iso4217::EUR).unwrap().amount());
+/// assert_eq!(2, bag.len());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct MoneyBag {
+    balances: CurrencyMap<i64>,
+}
+
+impl MoneyBag {
+    pub const fn new() -> MoneyBag {
+        MoneyBag {
+            balances: CurrencyMap::new(),
+        }
+    }
+
+    /// Adds every amount of `iter` to an empty bag.
+    pub fn try_from_iter<I: IntoIterator<Item = Money>>(iter: I) -> Result<MoneyBag, MoneyError> {
+        let mut bag = MoneyBag::new();
+        for money in iter {
+            bag.add(money)?;
+        }
+        Ok(bag)
+    }
+
+    /// Number of currencies held, zero balances included.
+    pub const fn len(&self) -> usize {
+        self.balances.len()
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        self.balances.is_empty()
+    }
+
+    /// Balance in `currency`, if any was added.
+    pub fn get(&self, currency: CurrencyCode) -> Option<Money> {
+        let amount = self.balances.get(currency.currency()?)?;
+        Some(Money::new(*amount, currency))
+    }
+
+    pub fn add(&mut self, money: Money) -> Result<(), MoneyError> {
+        self.apply(money, i64::checked_add)
+    }
+
+    pub fn sub(&mut self, money: Money) -> Result<(), MoneyError> {
+        self.apply(money, i64::checked_sub)
+    }
+
+    /// Negates every balance.
+    pub fn negate(&mut self) -> Result<(), MoneyError> {
+        if self.balances.values().any(|amount| *amount == i64::MIN) {
+            return Err(MoneyError::Overflow);
+        }
+        for (_, amount) in self.balances.iter_mut() {
+            *amount = -*amount;
+        }
+        Ok(())
+    }
+
+    /// Adds every balance of `other`.
+    pub fn merge(&mut self, other: &MoneyBag) -> Result<(), MoneyError> {
+        let mut merged = self.balances.clone();
+        for (currency, amount) in other.balances.iter() {
+            let balance = merged.entry_or_default(currency);
+            *balance = balance.checked_add(*amount).ok_or(MoneyError::Overflow)?;
+        }
+        self.balances = merged;
+        Ok(())
+    }
+
+    /// Removes the currencies whose balance is zero.
+    pub fn remove_zeros(&mut self) {
+        self.balances.retain(|_, amount| *amount != 0);
+    }
+
+    /// Iterates the balances in alpha code order.
+    pub fn iter(&self) -> impl Iterator<Item = Money> + '_ {
+        ALPHA_ORDER.iter().filter_map(|currency| {
+            let amount = self.balances.get(*currency)?;
+            Some(Money::new(*amount, *currency.currency_code()))
+        })
+    }
+
+    /// Converts every balance to `target` and sums them.
+    ///
+    /// `rate(from, to)` gives the units of `to` per unit of `from`. The
+    /// converted amounts are summed exactly and rounded once with `mode`.
+    /// #Sample
+    /// ```
+    /// use rust_iso4217::{Decimal, Money, MoneyBag, RoundingMode};
+    ///
+    /// let mut bag = MoneyBag::new();
+    /// bag.add(Money::from_major(10, rust_iso4217::EUR).unwrap()).unwrap();
+    /// bag.add(Money::from_major(5, rust_iso4217::USD).unwrap()).unwrap();
+    /// let total = bag
+    ///     .collapse(rust_iso4217::EUR, RoundingMode::HalfEven, |_, _| {
+    ///         Some(Decimal::new(92125, 5))
+    ///     })
+    ///     .unwrap();
+    /// assert_eq!("14.61 EUR", total.value.to_string());
+    /// ```
+    pub fn collapse(
+        &self,
+        target: CurrencyCode,
+        mode: RoundingMode,
+        mut rate: impl FnMut(CurrencyCode, CurrencyCode) -> Option<Decimal>,
+    ) -> Result<Rounded<Money>, MoneyError> {
+        let target_key = key(target)?;
+        let mut total = Decimal::ZERO;
+        for money in self.iter() {
+            let mut amount = money.to_decimal();
+            if money.currency().currency() != Some(target_key) {
+                let rate = rate(money.currency(), target)
+                    .ok_or(MoneyError::MissingRate(money.currency().code, target.code))?;
+                amount = amount.checked_mul(rate).ok_or(MoneyError::Overflow)?;
+            }
+            total = total.checked_add(amount).ok_or(MoneyError::Overflow)?;
+        }
+        target.round(total, mode)
+    }
+
+    fn apply(&mut self, money: Money, op: fn(i64, i64) -> Option<i64>) -> Result<(), MoneyError> {
+        let currency = key(money.currency())?;
+        let balance = self.balances.get(currency).copied().unwrap_or(0);
+        let balance = op(balance, money.amount()).ok_or(MoneyError::Overflow)?;
+        self.balances.insert(currency, balance);
+        Ok(())
+    }
+}
+
+fn key(currency: CurrencyCode) -> Result<Currency, MoneyError> {
+    Ok(Currency::try_from(currency)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Decimal, Money, MoneyBag, MoneyError, RoundingMode};
+
+    fn money(s: &str) -> Money {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn test_balances() {
+        let mut bag =
+            MoneyBag::try_from_iter(["1.50 USD", "100 JPY", "2.00 EUR", "-1.50 USD"].map(money))
+                .unwrap();
+        assert_eq!(3, bag.len());
+        assert_eq!(Some(money("0.00 USD")), bag.get(crate::USD));
+        assert_eq!(None, bag.get(crate::GBP));
+        bag.remove_zeros();
+        bag.add(money("1.00 AOA")).unwrap();
+        // Alpha code order, although Euro (Åland Islands) precedes Kwanza
+        // (Angola) in ALL_CODES.
+        let listed: Vec<_> = bag.iter().map(|m| m.to_string()).collect();
+        assert_eq!(vec!["1.00 AOA", "2.00 EUR", "100 JPY"], listed);
+        bag.sub(money("1.00 AOA")).unwrap();
+        bag.remove_zeros();
+
+        let mut other = MoneyBag::new();
+        other.add(money("0.001 KWD")).unwrap();
+        other.sub(money("3.00 EUR")).unwrap();
+        bag.merge(&other).unwrap();
+        bag.negate().unwrap();
+        assert_eq!(Some(money("1.00 EUR")), bag.get(crate::EUR));
+        assert_eq!(Some(money("-0.001 KWD")), bag.get(crate::KWD));
+
+        let max = Money::from_minor(i64::MAX, crate::EUR).unwrap();
+        assert_eq!(Err(MoneyError::Overflow), bag.add(max));
+        assert_eq!(Some(money("1.00 EUR")), bag.get(crate::EUR));
+        let mut overflow = MoneyBag::new();
+        overflow.add(money("1.000 KWD")).unwrap();
+        overflow.add(max).unwrap();
+        let mut merged = MoneyBag::new();
+        merged.merge(&overflow).unwrap();
+        // The KWD balance must not be applied when EUR overflows.
+        assert_eq!(Err(MoneyError::Overflow), merged.merge(&overflow));
+        assert_eq!(Some(money("1.000 KWD")), merged.get(crate::KWD));
+        assert_eq!(
+            Err(MoneyError::Overflow),
+            MoneyBag::try_from_iter([max, money("0.01 EUR")])
+        );
+        let mut min = MoneyBag::new();
+        min.add(max.with_amount(i64::MIN)).unwrap();
+        assert_eq!(Err(MoneyError::Overflow), min.negate());
+    }
+
+    #[test]
+    fn test_collapse() {
+        let bag =
+            MoneyBag::try_from_iter(["10.00 USD", "1000 JPY", "1.000 KWD", "5.00 EUR"].map(money))
+                .unwrap();
+        let rates = |from: crate::CurrencyCode, _| match from.code {
+            "USD" => Some(Decimal::new(92, 2)),
+            "JPY" => Some(Decimal::new(615, 5)),
+            "KWD" => Some(Decimal::new(2_985, 3)),
+            _ => None,
+        };
+        // 9.2 + 6.15 + 2.985 + 5 = 23.335
+        let total = bag
+            .collapse(crate::EUR, RoundingMode::HalfEven, rates)
+            .unwrap();
+        assert_eq!(money("23.34 EUR"), total.value);
+        assert_eq!("-0.005000", total.remainder.to_string());
+        assert_eq!(
+            Err(MoneyError::MissingRate("EUR", "GBP")),
+            bag.collapse(crate::GBP, RoundingMode::HalfEven, |from, _| {
+                (from.code != "EUR").then_some(Decimal::new(1, 0))
+            })
+        );
+        assert_eq!(
+            Ok(Money::zero(crate::CHF).unwrap()),
+            MoneyBag::new()
+                .collapse(crate::CHF, RoundingMode::Floor, |_, _| None)
+                .map(|total| total.value)
+        );
+    }
+}
@@ -0,0 +1,188 @@
+use core::fmt;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen::prelude::*;
+
+use crate::{Currency, CurrencyCode};
+
+/// ISO 4217 table a code is published in.
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum CodeType {
+    /// Table A.1 currency (including metals and special units).
+    Currency,
+    /// Table A.2 funds code.
+    Funds,
+    /// Table A.3 historic denomination.
+    Historic,
+}
+
+impl CodeType {
+    /// Returns the lower case name used by the data set, e.g. `"funds"`.
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            CodeType::Currency => "currency",
+            CodeType::Funds => "funds",
+            CodeType::Historic => "historic",
+        }
+    }
+}
+
+impl fmt::Display for CodeType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Classification predicates.
+/// #Sample
+/// ```
+/// assert!(rust_iso4217::USD.is_monetary());
+/// assert!(!rust_iso4217::XTS.is_monetary());
+/// assert!(rust_iso4217::XAU.is_precious_metal());
+/// assert!(rust_iso4217::DEM.is_historic());
+/// ```
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+impl CurrencyCode {
+    /// Currency or funds code that is still in use.
+    pub fn is_active(&self) -> bool {
+        self.code_type != CodeType::Historic
+    }
+
+    /// Table A.2 funds code such as `CLF` or `USN`.
+    pub fn is_fund(&self) -> bool {
+        self.code_type == CodeType::Funds
+    }
+
+    /// Withdrawn code from table A.3.
+    pub fn is_historic(&self) -> bool {
+        self.code_type == CodeType::Historic
+    }
+
+    /// Gold, silver, platinum or palladium (`XAU`, `XAG`, `XPT`, `XPD`).
+    pub fn is_precious_metal(&self) -> bool {
+        matches!(self.code.as_bytes(), b"XAU" | b"XAG" | b"XPT" | b"XPD")
+    }
+
+    /// European bond market units `XBA`, `XBB`, `XBC` and `XBD`.
+    pub fn is_bond_market_unit(&self) -> bool {
+        matches!(self.code.as_bytes(), b"XBA" | b"XBB" | b"XBC" | b"XBD")
+    }
+
+    /// IMF Special Drawing Right (`XDR`).
+    pub fn is_sdr(&self) -> bool {
+        self.code == "XDR"
+    }
+
+    /// Supranational unit of account: ADB Unit of Account (`XUA`), SUCRE
+    /// (`XSU`) and Arab Accounting Dinar (`XAD`).
+    pub fn is_supranational_unit(&self) -> bool {
+        matches!(self.code.as_bytes(), b"XUA" | b"XSU" | b"XAD")
+    }
+
+    /// Code reserved for testing (`XTS`).
+    pub fn is_testing(&self) -> bool {
+        self.code == "XTS"
+    }
+
+    /// Code for transactions where no currency is involved (`XXX`).
+    pub fn is_no_currency(&self) -> bool {
+        self.code == "XXX"
+    }
+
+    /// Active code that denotes actual money: none of the metals, bond
+    /// market units, SDR, supranational units, `XTS` or `XXX`.
+    pub fn is_monetary(&self) -> bool {
+        self.is_active()
+            && !self.is_precious_metal()
+            && !self.is_bond_market_unit()
+            && !self.is_sdr()
+            && !self.is_supranational_unit()
+            && !self.is_testing()
+            && !self.is_no_currency()
+    }
+}
+
+impl Currency {
+    /// See [`CurrencyCode::is_active`].
+    pub fn is_active(self) -> bool {
+        self.currency_code().is_active()
+    }
+
+    /// See [`CurrencyCode::is_fund`].
+    pub fn is_fund(self) -> bool {
+        self.currency_code().is_fund()
+    }
+
+    /// See [`CurrencyCode::is_historic`].
+    pub fn is_historic(self) -> bool {
+        self.currency_code().is_historic()
+    }
+
+    /// See [`CurrencyCode::is_precious_metal`].
+    pub fn is_precious_metal(self) -> bool {
+        self.currency_code().is_precious_metal()
+    }
+
+    /// See [`CurrencyCode::is_bond_market_unit`].
+    pub fn is_bond_market_unit(self) -> bool {
+        self.currency_code().is_bond_market_unit()
+    }
+
+    /// See [`CurrencyCode::is_sdr`].
+    pub fn is_sdr(self) -> bool {
+        self.currency_code().is_sdr()
+    }
+
+    /// See [`CurrencyCode::is_supranational_unit`].
+    pub fn is_supranational_unit(self) -> bool {
+        self.currency_code().is_supranational_unit()
+    }
+
+    /// See [`CurrencyCode::is_testing`].
+    pub fn is_testing(self) -> bool {
+        self.currency_code().is_testing()
+    }
+
+    /// See [`CurrencyCode::is_no_currency`].
+    pub fn is_no_currency(self) -> bool {
+        self.currency_code().is_no_currency()
+    }
+
+    /// See [`CurrencyCode::is_monetary`].
+    pub fn is_monetary(self) -> bool {
+        self.currency_code().is_monetary()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::CodeType;
+
+    #[test]
+    fn test_code_type_tables() {
+        for code in crate::ALL_CODES {
+            let table = match code.code_type {
+                CodeType::Currency => crate::ALL_ACTIVE_CODE,
+                CodeType::Funds => crate::ALL_FUNDS_CODE,
+                CodeType::Historic => crate::ALL_HISTORIC_CODE,
+            };
+            assert!(table.contains(&code.code), "{}", code.code);
+        }
+    }
+
+    #[test]
+    fn test_monetary() {
+        let special = [
+            "XAU", "XAG", "XPT", "XPD", "XBA", "XBB", "XBC", "XBD", "XDR",
+        ];
+        for code in special.iter().chain(&["XUA", "XSU", "XAD", "XTS", "XXX"]) {
+            let currency = crate::from_code(code).unwrap();
+            assert!(currency.is_active());
+            assert!(!currency.is_monetary(), "{}", code);
+        }
+        assert!(crate::EUR.is_monetary());
+        assert!(crate::CLF.is_fund() && crate::CLF.is_monetary());
+        assert!(!crate::DEM.is_monetary());
+    }
+}
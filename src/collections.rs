@@ -0,0 +1,453 @@
+//! Fixed-size collections keyed by [`Currency`], indexed by its `ALL_CODES`
+//! position instead of hashing.
+
+use core::fmt;
+use core::iter::FusedIterator;
+use core::ops::{BitAnd, BitOr, BitXor, Index, Sub};
+
+use crate::{Currency, ALL_CURRENCIES};
+
+const LEN: usize = ALL_CURRENCIES.len();
+const WORDS: usize = LEN.div_ceil(64);
+
+/// Bitset over every ISO 4217 code, one bit per [`Currency`].
+/// #Sample
+/// ```
+/// use rust_iso4217::{Currency, CurrencySet};
+///
+/// const ACCEPTED: CurrencySet = CurrencySet::new().with(Currency::USD).with(Currency::EUR);
+/// assert!(ACCEPTED.contains(Currency::EUR));
+/// assert_eq!(2, ACCEPTED.len());
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Default)]
+pub struct CurrencySet {
+    words: [u64; WORDS],
+}
+
+impl CurrencySet {
+    /// Returns an empty set.
+    pub const fn new() -> Self {
+        CurrencySet { words: [0; WORDS] }
+    }
+
+    /// Returns a set holding every code in `ALL_CODES`.
+    pub const fn all() -> Self {
+        let mut set = CurrencySet::new();
+        let mut i = 0;
+        while i < LEN {
+            set.words[i / 64] |= 1 << (i % 64);
+            i += 1;
+        }
+        set
+    }
+
+    /// Returns a copy of the set with `currency` added, for `const` building.
+    pub const fn with(mut self, currency: Currency) -> Self {
+        self.insert(currency);
+        self
+    }
+
+    /// Adds `currency`, returning whether it was newly inserted.
+    pub const fn insert(&mut self, currency: Currency) -> bool {
+        let (word, bit) = position(currency);
+        let added = self.words[word] & bit == 0;
+        self.words[word] |= bit;
+        added
+    }
+
+    /// Removes `currency`, returning whether it was present.
+    pub const fn remove(&mut self, currency: Currency) -> bool {
+        let (word, bit) = position(currency);
+        let present = self.words[word] & bit != 0;
+        self.words[word] &= !bit;
+        present
+    }
+
+    pub const fn contains(&self, currency: Currency) -> bool {
+        let (word, bit) = position(currency);
+        self.words[word] & bit != 0
+    }
+
+    pub const fn len(&self) -> usize {
+        let mut len = 0;
+        let mut i = 0;
+        while i < WORDS {
+            len += self.words[i].count_ones() as usize;
+            i += 1;
+        }
+        len
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub const fn union(&self, other: &CurrencySet) -> CurrencySet {
+        let mut set = *self;
+        let mut i = 0;
+        while i < WORDS {
+            set.words[i] |= other.words[i];
+            i += 1;
+        }
+        set
+    }
+
+    pub const fn intersection(&self, other: &CurrencySet) -> CurrencySet {
+        let mut set = *self;
+        let mut i = 0;
+        while i < WORDS {
+            set.words[i] &= other.words[i];
+            i += 1;
+        }
+        set
+    }
+
+    /// Codes in `self` but not in `other`.
+    pub const fn difference(&self, other: &CurrencySet) -> CurrencySet {
+        let mut set = *self;
+        let mut i = 0;
+        while i < WORDS {
+            set.words[i] &= !other.words[i];
+            i += 1;
+        }
+        set
+    }
+
+    /// Codes in exactly one of `self` and `other`.
+    pub const fn symmetric_difference(&self, other: &CurrencySet) -> CurrencySet {
+        let mut set = *self;
+        let mut i = 0;
+        while i < WORDS {
+            set.words[i] ^= other.words[i];
+            i += 1;
+        }
+        set
+    }
+
+    pub const fn is_subset(&self, other: &CurrencySet) -> bool {
+        self.difference(other).is_empty()
+    }
+
+    /// Iterates in `ALL_CODES` order.
+    pub fn iter(&self) -> CurrencySetIter {
+        CurrencySetIter {
+            words: self.words,
+            word: 0,
+        }
+    }
+}
+
+const fn position(currency: Currency) -> (usize, u64) {
+    let index = currency.index() as usize;
+    (index / 64, 1 << (index % 64))
+}
+
+impl fmt::Debug for CurrencySet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl FromIterator<Currency> for CurrencySet {
+    fn from_iter<I: IntoIterator<Item = Currency>>(iter: I) -> Self {
+        let mut set = CurrencySet::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl Extend<Currency> for CurrencySet {
+    fn extend<I: IntoIterator<Item = Currency>>(&mut self, iter: I) {
+        for currency in iter {
+            self.insert(currency);
+        }
+    }
+}
+
+impl IntoIterator for CurrencySet {
+    type Item = Currency;
+    type IntoIter = CurrencySetIter;
+
+    fn into_iter(self) -> CurrencySetIter {
+        self.iter()
+    }
+}
+
+impl IntoIterator for &CurrencySet {
+    type Item = Currency;
+    type IntoIter = CurrencySetIter;
+
+    fn into_iter(self) -> CurrencySetIter {
+        self.iter()
+    }
+}
+
+impl BitOr for CurrencySet {
+    type Output = CurrencySet;
+
+    fn bitor(self, rhs: CurrencySet) -> CurrencySet {
+        self.union(&rhs)
+    }
+}
+
+impl BitAnd for CurrencySet {
+    type Output = CurrencySet;
+
+    fn bitand(self, rhs: CurrencySet) -> CurrencySet {
+        self.intersection(&rhs)
+    }
+}
+
+impl BitXor for CurrencySet {
+    type Output = CurrencySet;
+
+    fn bitxor(self, rhs: CurrencySet) -> CurrencySet {
+        self.symmetric_difference(&rhs)
+    }
+}
+
+impl Sub for CurrencySet {
+    type Output = CurrencySet;
+
+    fn sub(self, rhs: CurrencySet) -> CurrencySet {
+        self.difference(&rhs)
+    }
+}
+
+/// Iterator over a [`CurrencySet`] in `ALL_CODES` order.
+#[derive(Clone, Debug)]
+pub struct CurrencySetIter {
+    words: [u64; WORDS],
+    word: usize,
+}
+
+impl Iterator for CurrencySetIter {
+    type Item = Currency;
+
+    fn next(&mut self) -> Option<Currency> {
+        while self.word < WORDS {
+            let bits = self.words[self.word];
+            if bits != 0 {
+                self.words[self.word] = bits & (bits - 1);
+                let index = self.word * 64 + bits.trailing_zeros() as usize;
+                return Some(ALL_CURRENCIES[index]);
+            }
+            self.word += 1;
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.words[self.word.min(WORDS)..]
+            .iter()
+            .map(|w| w.count_ones() as usize)
+            .sum();
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for CurrencySetIter {}
+
+impl FusedIterator for CurrencySetIter {}
+
+/// Dense map with one slot per [`Currency`], e.g. per-currency balances.
+/// #Sample
+/// ```
+/// use rust_iso4217::{Currency, CurrencyMap};
+///
+/// let mut balances = CurrencyMap::new();
+/// balances.insert(Currency::JPY, 1_000);
+/// *balances.entry_or_default(Currency::EUR) += 250;
+/// assert_eq!(Some(&250), balances.get(Currency::EUR));
+/// assert_eq!(2, balances.len());
+/// ```
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct CurrencyMap<V> {
+    slots: [Option<V>; LEN],
+    len: usize,
+}
+
+impl<V> CurrencyMap<V> {
+    /// Returns an empty map.
+    pub const fn new() -> Self {
+        CurrencyMap {
+            slots: [const { None }; LEN],
+            len: 0,
+        }
+    }
+
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub const fn get(&self, currency: Currency) -> Option<&V> {
+        self.slots[currency.index() as usize].as_ref()
+    }
+
+    pub fn get_mut(&mut self, currency: Currency) -> Option<&mut V> {
+        self.slots[currency.index() as usize].as_mut()
+    }
+
+    pub const fn contains_key(&self, currency: Currency) -> bool {
+        self.get(currency).is_some()
+    }
+
+    /// Inserts `value`, returning the previous value for `currency`.
+    pub fn insert(&mut self, currency: Currency, value: V) -> Option<V> {
+        let old = self.slots[currency.index() as usize].replace(value);
+        if old.is_none() {
+            self.len += 1;
+        }
+        old
+    }
+
+    pub fn remove(&mut self, currency: Currency) -> Option<V> {
+        let old = self.slots[currency.index() as usize].take();
+        if old.is_some() {
+            self.len -= 1;
+        }
+        old
+    }
+
+    /// Returns the value for `currency`, inserting `V::default()` if absent.
+    pub fn entry_or_default(&mut self, currency: Currency) -> &mut V
+    where
+        V: Default,
+    {
+        let slot = &mut self.slots[currency.index() as usize];
+        if slot.is_none() {
+            self.len += 1;
+        }
+        slot.get_or_insert_with(V::default)
+    }
+
+    /// Keeps only the entries for which `keep` returns `true`.
+    pub fn retain(&mut self, mut keep: impl FnMut(Currency, &mut V) -> bool) {
+        for (i, slot) in self.slots.iter_mut().enumerate() {
+            if let Some(value) = slot {
+                if !keep(ALL_CURRENCIES[i], value) {
+                    *slot = None;
+                    self.len -= 1;
+                }
+            }
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.slots = [const { None }; LEN];
+        self.len = 0;
+    }
+
+    /// Returns the set of currencies holding a value.
+    pub fn keys(&self) -> CurrencySet {
+        self.iter().map(|(currency, _)| currency).collect()
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &V> {
+        self.slots.iter().flatten()
+    }
+
+    /// Iterates in `ALL_CODES` order.
+    pub fn iter(&self) -> impl Iterator<Item = (Currency, &V)> {
+        self.slots
+            .iter()
+            .zip(ALL_CURRENCIES)
+            .filter_map(|(slot, currency)| slot.as_ref().map(|v| (*currency, v)))
+    }
+
+    /// Iterates in `ALL_CODES` order.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Currency, &mut V)> {
+        self.slots
+            .iter_mut()
+            .zip(ALL_CURRENCIES)
+            .filter_map(|(slot, currency)| slot.as_mut().map(|v| (*currency, v)))
+    }
+}
+
+impl<V> Default for CurrencyMap<V> {
+    fn default() -> Self {
+        CurrencyMap::new()
+    }
+}
+
+impl<V: fmt::Debug> fmt::Debug for CurrencyMap<V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<V> Index<Currency> for CurrencyMap<V> {
+    type Output = V;
+
+    /// Panics when `currency` has no value.
+    fn index(&self, currency: Currency) -> &V {
+        match self.get(currency) {
+            Some(value) => value,
+            None => panic!("no value for {}", currency),
+        }
+    }
+}
+
+impl<V> FromIterator<(Currency, V)> for CurrencyMap<V> {
+    fn from_iter<I: IntoIterator<Item = (Currency, V)>>(iter: I) -> Self {
+        let mut map = CurrencyMap::new();
+        map.extend(iter);
+        map
+    }
+}
+
+impl<V> Extend<(Currency, V)> for CurrencyMap<V> {
+    fn extend<I: IntoIterator<Item = (Currency, V)>>(&mut self, iter: I) {
+        for (currency, value) in iter {
+            self.insert(currency, value);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Currency, CurrencyMap, CurrencySet};
+
+    #[test]
+    fn test_set_operations() {
+        let a: CurrencySet = [Currency::USD, Currency::EUR, Currency::XFU]
+            .into_iter()
+            .collect();
+        let b = CurrencySet::new().with(Currency::EUR).with(Currency::JPY);
+        assert_eq!(vec![Currency::EUR], (a & b).iter().collect::<Vec<_>>());
+        assert_eq!(4, (a | b).len());
+        assert_eq!(
+            vec![Currency::USD, Currency::XFU],
+            (a - b).iter().collect::<Vec<_>>()
+        );
+        assert_eq!(3, (a ^ b).len());
+        assert!((a & b).is_subset(&a));
+        let all = CurrencySet::all();
+        assert_eq!(crate::ALL_CODES.len(), all.len());
+        assert!(all.iter().eq(Currency::iter()));
+        assert_eq!(crate::ALL_CODES.len(), all.iter().len());
+    }
+
+    #[test]
+    fn test_map() {
+        let mut map: CurrencyMap<i64> = CurrencyMap::new();
+        assert!(map.is_empty());
+        assert_eq!(None, map.insert(Currency::XFU, 1));
+        assert_eq!(Some(1), map.insert(Currency::XFU, 2));
+        *map.entry_or_default(Currency::AFN) += 5;
+        assert_eq!(2, map.len());
+        assert_eq!(
+            vec![(Currency::AFN, &5), (Currency::XFU, &2)],
+            map.iter().collect::<Vec<_>>()
+        );
+        map.retain(|_, v| *v > 2);
+        assert_eq!(1, map.len());
+        assert_eq!(5, map[Currency::AFN]);
+        assert_eq!(Some(5), map.remove(Currency::AFN));
+        assert!(map.is_empty());
+    }
+}
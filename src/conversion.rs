@@ -0,0 +1,176 @@
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::{
+    convert, CurrencyCode, Date, Decimal, ExchangeRate, Money, MoneyError, RateProvider,
+    RoundingMode,
+};
+
+/// Everything that produced a converted amount, for audit trails.
+///
+/// [`Conversion::replay`] recomputes the result from the recorded input,
+/// legs and rounding mode alone; with the `serde` feature the record
+/// serializes (and deserializes) losslessly.
+/// #Sample
+/// ```
+/// use rust_iso4217::{convert_with, OfflineRates, RateFormat, RoundingMode, Money};
+///
+/// let mut ecb = OfflineRates::new().with_name("ECB");
+/// ecb.load("2025-10-17,EUR,USD,1.1681\n", RateFormat::Csv).unwrap();
+/// let price = Money::from_major(250, rust_iso4217::EUR).unwrap();
+/// let conversion = convert_with(price, rust_iso4217::USD, &ecb, None, RoundingMode::HalfEven).unwrap();
+/// assert_eq!("292.02 USD", conversion.output.to_string());
+/// assert_eq!("ECB", conversion.provider);
+/// assert_eq!("0.005000", conversion.remainder.to_string());
+/// assert_eq!(Ok(conversion.clone()), conversion.replay());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Conversion {
+    pub input: Money,
+    pub output: Money,
+    /// The rate applied, composed from `legs`.
+    pub rate: ExchangeRate,
+    /// Rates chained from the input to the output currency.
+    pub legs: Vec<ExchangeRate>,
+    /// [`RateProvider::name`] of the source; empty when rates were given.
+    pub provider: String,
+    /// Date the rates were asked for; `None` for the latest rates.
+    pub as_of: Option<Date>,
+    pub mode: RoundingMode,
+    /// What rounding dropped, in the output currency:
+    /// `input × rate = output + remainder`.
+    pub remainder: Decimal,
+}
+
+impl Conversion {
+    /// Converts `input` through `legs` in order and records it.
+    ///
+    /// With no legs the input is returned unchanged at rate 1.
+    pub fn new(
+        input: Money,
+        legs: Vec<ExchangeRate>,
+        mode: RoundingMode,
+    ) -> Result<Conversion, MoneyError> {
+        let rate = match legs.split_first() {
+            Some((first, rest)) => {
+                let mut rate = *first;
+                for leg in rest {
+                    rate = rate.cross(leg)?;
+                }
+                rate
+            }
+            None => ExchangeRate::new(input.currency(), input.currency(), Decimal::new(1, 0))?,
+        };
+        let rounded = convert(input, &rate, mode)?;
+        Ok(Conversion {
+            input,
+            output: rounded.value,
+            rate,
+            legs,
+            provider: String::new(),
+            as_of: None,
+            mode,
+            remainder: rounded.remainder,
+        })
+    }
+
+    /// Recomputes the conversion from the recorded input, legs and mode,
+    /// keeping the recorded provider and date.
+    ///
+    /// A faithful record replays to itself.
+    pub fn replay(&self) -> Result<Conversion, MoneyError> {
+        let replayed = Conversion::new(self.input, self.legs.clone(), self.mode)?;
+        Ok(Conversion {
+            provider: self.provider.clone(),
+            as_of: self.as_of,
+            ..replayed
+        })
+    }
+}
+
+/// Converts `money` into `quote` with the provider's direct rate, the latest
+/// one or the one for `as_of`, and records how.
+pub fn convert_with<P: RateProvider + ?Sized>(
+    money: Money,
+    quote: CurrencyCode,
+    provider: &P,
+    as_of: Option<Date>,
+    mode: RoundingMode,
+) -> Result<Conversion, MoneyError> {
+    let base = money.currency();
+    let rate = match as_of {
+        Some(date) => provider.rate_on(base, quote, date),
+        None => provider.rate(base, quote),
+    }
+    .ok_or(MoneyError::MissingRate(base.code, quote.code))?;
+    let conversion = Conversion::new(money, vec![rate], mode)?;
+    Ok(Conversion {
+        provider: provider.name().to_string(),
+        as_of,
+        ..conversion
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        convert_with, Conversion, Date, Money, MoneyError, OfflineRates, RateFormat, RateSeries,
+        RoundingMode, Triangulator,
+    };
+
+    fn rates() -> OfflineRates {
+        let mut rates = OfflineRates::new().with_name("fixture");
+        rates
+            .load(
+                "2025-10-16,EUR,USD,1.1697\n2025-10-17,EUR,USD,1.1681\n2025-10-17,USD,JPY,150.25\n",
+                RateFormat::Csv,
+            )
+            .unwrap();
+        rates
+    }
+
+    #[test]
+    fn test_provenance() {
+        let rates = rates();
+        let eur = Money::from_decimal_str("99.99", crate::EUR).unwrap();
+        let day = Date::new(2025, 10, 16).unwrap();
+        let conversion =
+            convert_with(eur, crate::USD, &rates, Some(day), RoundingMode::Floor).unwrap();
+        // 99.99 * 1.1697 = 116.958303
+        assert_eq!("116.95 USD", conversion.output.to_string());
+        assert_eq!("0.008303", conversion.remainder.to_string());
+        assert_eq!(Some(day), conversion.as_of);
+        assert_eq!(1, conversion.legs.len());
+        assert_eq!(Ok(conversion.clone()), conversion.replay());
+
+        let triangulated = Triangulator::new(&rates)
+            .convert(eur, crate::JPY, None, RoundingMode::HalfUp)
+            .unwrap();
+        assert_eq!("fixture", triangulated.provider);
+        assert_eq!(2, triangulated.legs.len());
+        // 99.99 * 1.1681 * 150.25 = 17548.7339475
+        assert_eq!("17549 JPY", triangulated.output.to_string());
+        assert_eq!(Ok(triangulated.clone()), triangulated.replay());
+
+        let mut tampered = triangulated.clone();
+        tampered.legs[1] = tampered.legs[0];
+        assert!(tampered.replay().is_err());
+        let mut tampered = triangulated;
+        tampered.output = tampered.output.with_amount(17_548);
+        assert_ne!(Ok(tampered.clone()), tampered.replay());
+
+        assert_eq!(
+            Err(MoneyError::MissingRate("EUR", "GBP")),
+            convert_with(
+                eur,
+                crate::GBP,
+                &RateSeries::new(),
+                None,
+                RoundingMode::Floor
+            )
+        );
+        let same = Conversion::new(eur, vec![], RoundingMode::Floor).unwrap();
+        assert_eq!(eur, same.output);
+    }
+}
@@ -0,0 +1,299 @@
+use crate::history::MERGED;
+use crate::{lookup_country, CountryError, Currency, CurrencySet, Date};
+
+/// Currencies each country used before the ones in `COUNTRY_MAP`.
+///
+/// A currency is in force from the date its own predecessor in the same
+/// country was replaced, or every earlier date when it has none listed,
+/// until its [`Replacement`](crate::Replacement) takes effect or its last
+/// [`Withdrawal`](crate::Withdrawal) period ends, whichever is later.
+const FORMER_CURRENCIES: &[(&str, &[Currency])] = &[
+    ("AFG", &[Currency::AFA]),
+    ("AGO", &[Currency::AOK, Currency::AON, Currency::AOR]),
+    ("ALA", &[Currency::FIM]),
+    ("ALB", &[Currency::ALK]),
+    ("AND", &[Currency::ADP, Currency::ESP, Currency::FRF]),
+    ("ARG", &[Currency::ARY, Currency::ARP, Currency::ARA]),
+    ("ATF", &[Currency::FRF]),
+    ("AUT", &[Currency::ATS]),
+    ("AZE", &[Currency::AYM, Currency::AZM]),
+    ("BEL", &[Currency::BEC, Currency::BEL, Currency::BEF]),
+    (
+        "BGR",
+        &[Currency::BGJ, Currency::BGK, Currency::BGL, Currency::BGN],
+    ),
+    ("BIH", &[Currency::BAD]),
+    ("BLM", &[Currency::FRF]),
+    ("BLR", &[Currency::BYB, Currency::BYR]),
+    ("BOL", &[Currency::BOP]),
+    (
+        "BRA",
+        &[
+            Currency::BRB,
+            Currency::BRC,
+            Currency::BRN,
+            Currency::BRE,
+            Currency::BRR,
+        ],
+    ),
+    ("COD", &[Currency::ZRZ, Currency::ZRN]),
+    ("CUB", &[Currency::CUC]),
+    ("CUW", &[Currency::ANG]),
+    ("CYP", &[Currency::CYP]),
+    ("DEU", &[Currency::DDM, Currency::DEM]),
+    ("ECU", &[Currency::ECS]),
+    ("ESP", &[Currency::ESA, Currency::ESB, Currency::ESP]),
+    ("EST", &[Currency::EEK]),
+    ("FIN", &[Currency::FIM]),
+    ("FRA", &[Currency::FRF]),
+    ("GEO", &[Currency::GEK]),
+    ("GHA", &[Currency::GHC, Currency::GHP]),
+    ("GIN", &[Currency::GNE, Currency::GNS]),
+    ("GLP", &[Currency::FRF]),
+    ("GNB", &[Currency::GWE, Currency::GWP]),
+    ("GNQ", &[Currency::GQE]),
+    ("GRC", &[Currency::GRD]),
+    ("GUF", &[Currency::FRF]),
+    ("HRV", &[Currency::HRD, Currency::HRK]),
+    ("IRL", &[Currency::IEP]),
+    ("ISL", &[Currency::ISJ]),
+    ("ISR", &[Currency::ILP, Currency::ILR]),
+    ("ITA", &[Currency::ITL]),
+    ("LAO", &[Currency::LAJ]),
+    ("LSO", &[Currency::LSM, Currency::ZAL]),
+    ("LTU", &[Currency::LTT, Currency::LTL]),
+    ("LUX", &[Currency::LUC, Currency::LUL, Currency::LUF]),
+    ("LVA", &[Currency::LVR, Currency::LVL]),
+    ("MAF", &[Currency::FRF]),
+    ("MCO", &[Currency::FRF]),
+    ("MDG", &[Currency::MGF]),
+    ("MDV", &[Currency::MVQ]),
+    ("MEX", &[Currency::MXP]),
+    ("MLI", &[Currency::MLF]),
+    ("MLT", &[Currency::MTP, Currency::MTL]),
+    ("MMR", &[Currency::BUK]),
+    ("MNE", &[Currency::DEM]),
+    ("MOZ", &[Currency::MZE, Currency::MZM]),
+    ("MRT", &[Currency::MRO]),
+    ("MTQ", &[Currency::FRF]),
+    ("MYT", &[Currency::FRF]),
+    ("NIC", &[Currency::NIC]),
+    ("NLD", &[Currency::NLG]),
+    ("PER", &[Currency::PES, Currency::PEH, Currency::PEI]),
+    ("POL", &[Currency::PLZ]),
+    ("PRT", &[Currency::PTE]),
+    ("REU", &[Currency::FRF]),
+    ("ROU", &[Currency::ROK, Currency::ROL]),
+    ("RUS", &[Currency::SUR, Currency::RUR]),
+    ("SDN", &[Currency::SDP, Currency::SDD]),
+    ("SLE", &[Currency::SLL]),
+    ("SMR", &[Currency::ITL]),
+    ("SPM", &[Currency::FRF]),
+    (
+        "SRB",
+        &[Currency::YUD, Currency::YUN, Currency::YUM, Currency::CSD],
+    ),
+    ("STP", &[Currency::STD]),
+    ("SUR", &[Currency::SRG]),
+    ("SVK", &[Currency::SKK]),
+    ("SVN", &[Currency::SIT]),
+    ("SXM", &[Currency::ANG]),
+    ("TJK", &[Currency::TJR]),
+    ("TKM", &[Currency::TMM]),
+    ("TUR", &[Currency::TRL]),
+    ("UGA", &[Currency::UGW, Currency::UGS]),
+    ("UKR", &[Currency::UAK]),
+    ("URY", &[Currency::UYN, Currency::UYP]),
+    ("USA", &[Currency::USS]),
+    ("VAT", &[Currency::ITL]),
+    ("VEN", &[Currency::VEB, Currency::VEF]),
+    ("VNM", &[Currency::VNC]),
+    ("YEM", &[Currency::YDD]),
+    ("ZAF", &[Currency::ZAL]),
+    ("ZMB", &[Currency::ZMK]),
+    (
+        "ZWE",
+        &[
+            Currency::RHD,
+            Currency::ZWC,
+            Currency::ZWD,
+            Currency::ZWN,
+            Currency::ZWR,
+            Currency::ZWL,
+        ],
+    ),
+];
+
+/// When current currencies reached a country, where its replacements do not
+/// say: introduced alongside an existing one, or adopted there later than
+/// elsewhere.
+const INTRODUCED: &[(&str, Currency, Date)] = &[
+    ("MNE", Currency::EUR, Date::new(2002, 1, 1).unwrap()),
+    ("VEN", Currency::VED, Date::new(2021, 10, 1).unwrap()),
+];
+
+/// Returns the currencies in force in the given ISO 3166-1 alpha-3 country
+/// on `date`, case-insensitively.
+///
+/// Legacy currencies stay in force alongside their replacement until the
+/// end of their withdrawal period. Dates before the earliest change known
+/// for a country get its earliest known currencies, or the present ones
+/// when none is recorded.
+/// #Sample
+/// ```
+/// use rust_iso4217::{Currency, CurrencySet, Date};
+///
+/// let in_2019 = Date::new(2019, 6, 30).unwrap();
+/// let currencies = rust_iso4217::from_country_on("HRV", in_2019).unwrap();
+/// assert_eq!(CurrencySet::new().with(Currency::HRK), currencies);
+/// let today = Date::new(2026, 10, 18).unwrap();
+/// assert!(rust_iso4217::from_country_on("bgr", today).unwrap().contains(Currency::EUR));
+/// ```
+pub fn from_country_on(country: &str, date: Date) -> Result<CurrencySet, CountryError> {
+    let present = lookup_country(country)?;
+    let formers: &[Currency] = FORMER_CURRENCIES
+        .iter()
+        .find(|(code, _)| code.eq_ignore_ascii_case(country))
+        .map_or(&[], |(_, formers)| formers);
+    // When `currency` started: when a former currency was replaced by it.
+    let start = |currency: Currency| {
+        formers
+            .iter()
+            .filter(|former| !MERGED.contains(former))
+            .filter_map(|former| former.replacement())
+            .filter(|replacement| replacement.new == currency)
+            .map(|replacement| replacement.effective)
+            .chain(
+                INTRODUCED
+                    .iter()
+                    .filter(|(code, introduced, _)| {
+                        code.eq_ignore_ascii_case(country) && *introduced == currency
+                    })
+                    .map(|(_, _, date)| *date),
+            )
+            .max()
+    };
+    let in_force = |currency: Currency| start(currency).is_none_or(|start| start <= date);
+    let mut currencies: CurrencySet = formers
+        .iter()
+        .copied()
+        .filter(|former| {
+            in_force(*former)
+                && (former
+                    .replacement()
+                    .is_none_or(|replacement| date < replacement.effective)
+                    || former
+                        .withdrawals()
+                        .iter()
+                        .any(|withdrawal| date <= withdrawal.until))
+        })
+        .collect();
+    currencies.extend(
+        present
+            .iter()
+            .filter_map(|code| code.currency())
+            .filter(|currency| in_force(*currency)),
+    );
+    Ok(currencies)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        from_country_on, try_from_country, CodeType, CountryError, Currency, CurrencySet, Date,
+    };
+
+    use super::FORMER_CURRENCIES;
+
+    fn on(country: &str, date: &str) -> Vec<&'static str> {
+        from_country_on(country, date.parse().unwrap())
+            .unwrap()
+            .iter()
+            .map(|c| c.code())
+            .collect()
+    }
+
+    #[test]
+    fn test_former_currencies() {
+        for (country, formers) in FORMER_CURRENCIES {
+            let present = try_from_country(country).unwrap();
+            for former in formers.iter() {
+                let current = former.resolve_current().unwrap();
+                assert!(
+                    present.iter().any(|c| c.currency() == Some(current)),
+                    "{} {}",
+                    country,
+                    former
+                );
+            }
+            // After the last recorded switch, the present-day list is back.
+            let today = Date::new(2100, 1, 1).unwrap();
+            let now: CurrencySet = present.iter().filter_map(|c| c.currency()).collect();
+            assert_eq!(now, from_country_on(country, today).unwrap(), "{}", country);
+        }
+    }
+
+    #[test]
+    fn test_former_currencies_complete() {
+        // Not issued by a country.
+        let supranational = [Currency::XEU, Currency::XFO];
+        for old in Currency::iter().filter(|c| c.replacement().is_some()) {
+            let withdrawals = old.withdrawals();
+            let single = withdrawals
+                .iter()
+                .all(|w| w.entity == withdrawals[0].entity);
+            let current = old.resolve_current().map(|c| c.currency_code().code_type);
+            if !single || supranational.contains(&old) || current != Some(CodeType::Currency) {
+                continue;
+            }
+            assert!(
+                FORMER_CURRENCIES
+                    .iter()
+                    .any(|(_, formers)| formers.contains(&old)),
+                "{}",
+                old
+            );
+        }
+    }
+
+    #[test]
+    fn test_from_country_on() {
+        assert_eq!(vec!["HRK"], on("HRV", "2022-12-31"));
+        assert_eq!(vec!["EUR", "HRK"], on("HRV", "2023-01-01"));
+        assert_eq!(vec!["EUR"], on("HRV", "2023-02-01"));
+        // Marks stayed legal tender until March 2002.
+        assert_eq!(vec!["EUR", "DEM"], on("DEU", "2000-06-30"));
+        assert_eq!(vec!["EUR"], on("DEU", "2002-04-01"));
+        assert_eq!(vec!["SLE", "SLL"], on("SLE", "2023-06-30"));
+        assert_eq!(vec!["HRD"], on("HRV", "1993-01-01"));
+        assert_eq!(vec!["BGN"], on("BGR", "2025-12-31"));
+        assert_eq!(vec!["DEM"], on("deu", "1998-12-31"));
+        assert_eq!(vec!["GRD"], on("GRC", "2000-06-30"));
+        assert_eq!(vec!["ZWD"], on("ZWE", "2005-01-01"));
+        assert_eq!(vec!["ZWR"], on("ZWE", "2008-12-01"));
+        assert_eq!(vec!["ZWG", "ZWL"], on("ZWE", "2024-04-05"));
+        assert_eq!(vec!["VEF"], on("VEN", "2015-01-01"));
+        assert_eq!(vec!["VES"], on("VEN", "2020-01-01"));
+        assert_eq!(vec!["VES", "VED"], on("VEN", "2022-01-01"));
+        assert_eq!(vec!["SLL"], on("SLE", "2022-06-30"));
+        assert_eq!(vec!["ANG"], on("CUW", "2020-01-01"));
+        assert_eq!(vec!["ADP", "ESP", "FRF"], on("AND", "1998-01-01"));
+        assert_eq!(vec!["CHF"], on("CHE", "1950-01-01"));
+        assert_eq!(vec!["MLF"], on("MLI", "1980-01-01"));
+        assert_eq!(vec!["XOF"], on("MLI", "1985-01-01"));
+        assert_eq!(vec!["CUP", "CUC"], on("CUB", "2015-01-01"));
+        assert_eq!(vec!["BUK"], on("MMR", "1985-01-01"));
+        // Both codes the list gives the sol.
+        assert_eq!(vec!["PEH", "PES"], on("PER", "1980-01-01"));
+        assert_eq!(vec!["DEM"], on("MNE", "2000-01-01"));
+        assert_eq!(vec!["EUR", "DEM"], on("MNE", "2002-02-01"));
+        assert_eq!(vec!["BEC", "BEF", "BEL"], on("BEL", "1985-01-01"));
+        assert_eq!(
+            Err(CountryError::UnknownCountry),
+            from_country_on("ZZZ", Date::new(2000, 1, 1).unwrap())
+        );
+        assert!(from_country_on("DEU", Date::new(1999, 1, 1).unwrap())
+            .unwrap()
+            .contains(Currency::EUR));
+    }
+}
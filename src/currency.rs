@@ -0,0 +1,156 @@
+use core::fmt;
+
+use crate::{CodeType, Currency, CurrencyCode, ParseCurrencyError, ALL_CODES, ALL_CURRENCIES};
+
+impl Currency {
+    /// Returns the CurrencyCode this variant stands for.
+    /// #Sample
+    /// ```
+    /// use rust_iso4217::Currency;
+    ///
+    /// assert_eq!(rust_iso4217::JPY, *Currency::JPY.currency_code());
+    /// ```
+    pub const fn currency_code(self) -> &'static CurrencyCode {
+        &ALL_CODES[self as usize]
+    }
+
+    /// English name, e.g. `"Euro"`.
+    pub const fn name(self) -> &'static str {
+        self.currency_code().name
+    }
+
+    /// Alpha3 code, e.g. `"EUR"`.
+    pub const fn code(self) -> &'static str {
+        self.currency_code().code
+    }
+
+    /// ISO number, `-1` for historic codes without one.
+    pub const fn numeric(self) -> i32 {
+        self.currency_code().numeric
+    }
+
+    /// Minor unit, `-1` when not applicable.
+    pub const fn unit(self) -> i32 {
+        self.currency_code().unit
+    }
+
+    /// ISO 4217 table the code is published in.
+    pub const fn code_type(self) -> CodeType {
+        self.currency_code().code_type
+    }
+
+    /// ISO 3166 alpha3 codes of the countries using this currency.
+    pub const fn countries(self) -> &'static [&'static str] {
+        self.currency_code().countries
+    }
+
+    /// Position in `ALL_CODES`; also the `u16` representation of the variant.
+    pub const fn index(self) -> u16 {
+        self as u16
+    }
+
+    /// Returns the variant at the given `ALL_CODES` position, if exists.
+    /// #Sample
+    /// ```
+    /// use rust_iso4217::Currency;
+    ///
+    /// assert_eq!(Some(Currency::CHF), Currency::from_index(Currency::CHF.index()));
+    /// ```
+    pub const fn from_index(index: u16) -> Option<Currency> {
+        if (index as usize) < ALL_CURRENCIES.len() {
+            Some(ALL_CURRENCIES[index as usize])
+        } else {
+            None
+        }
+    }
+
+    /// Iterates over every variant in `ALL_CODES` order.
+    /// #Sample
+    /// ```
+    /// use rust_iso4217::Currency;
+    ///
+    /// assert_eq!(rust_iso4217::ALL_CODES.len(), Currency::iter().count());
+    /// ```
+    pub fn iter() -> impl Iterator<Item = Currency> {
+        ALL_CURRENCIES.iter().copied()
+    }
+}
+
+impl fmt::Display for Currency {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+impl From<Currency> for u16 {
+    fn from(currency: Currency) -> Self {
+        currency.index()
+    }
+}
+
+impl From<Currency> for CurrencyCode {
+    fn from(currency: Currency) -> Self {
+        *currency.currency_code()
+    }
+}
+
+/// Resolves the variant by alpha3 code.
+impl TryFrom<CurrencyCode> for Currency {
+    type Error = ParseCurrencyError;
+
+    fn try_from(code: CurrencyCode) -> Result<Self, Self::Error> {
+        code.code.parse()
+    }
+}
+
+impl CurrencyCode {
+    /// Returns the compact [`Currency`] handle for this code, e.g. straight
+    /// from an `ALL_MAP` or `NUMERIC_MAP` lookup.
+    /// #Sample
+    /// ```
+    /// let usd = rust_iso4217::ALL_MAP.get("USD").and_then(|c| c.currency());
+    /// assert_eq!(Some(rust_iso4217::Currency::USD), usd);
+    /// ```
+    pub fn currency(&self) -> Option<Currency> {
+        Currency::try_from(*self).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Currency, CurrencyCode};
+
+    #[test]
+    fn test_round_trip() {
+        for (currency, code) in Currency::iter().zip(crate::ALL_CODES) {
+            assert_eq!(*code, CurrencyCode::from(currency));
+            assert_eq!(Ok(currency), Currency::try_from(*code));
+        }
+    }
+
+    #[test]
+    fn test_accessors() {
+        const KWD: Currency = Currency::KWD;
+        assert_eq!("Kuwaiti Dinar", KWD.name());
+        assert_eq!(414, KWD.numeric());
+        assert_eq!(3, KWD.unit());
+        assert_eq!(crate::CodeType::Currency, KWD.code_type());
+        assert_eq!(&["KWT"], KWD.countries());
+        assert_eq!(Ok(Currency::ALL), "008".parse());
+        assert_eq!("ALL", Currency::ALL.to_string());
+    }
+
+    #[test]
+    fn test_handle() {
+        assert_eq!(2, core::mem::size_of::<Currency>());
+        assert_eq!(2, core::mem::size_of::<Option<Currency>>());
+        for (i, currency) in Currency::iter().enumerate() {
+            assert_eq!(i as u16, u16::from(currency));
+            assert_eq!(Some(currency), Currency::from_index(i as u16));
+        }
+        assert_eq!(None, Currency::from_index(crate::ALL_CODES.len() as u16));
+        assert!(Currency::AFN < Currency::EUR);
+        let numeric = crate::NUMERIC_MAP.get("392").and_then(|c| c.currency());
+        assert_eq!(Some(Currency::JPY), numeric);
+    }
+}
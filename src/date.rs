@@ -0,0 +1,173 @@
+use core::fmt;
+use core::str::FromStr;
+
+use crate::DateError;
+
+/// A proleptic Gregorian calendar date, e.g. `2025-10-17`.
+/// #Sample
+/// ```
+/// use rust_iso4217::Date;
+///
+/// let date: Date = "2024-02-28".parse().unwrap();
+/// assert_eq!(Date::new(2024, 2, 29), Some(date.add_days(1)));
+/// assert_eq!(19_781, date.days_since_epoch());
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Date {
+    year: i32,
+    month: u8,
+    day: u8,
+}
+
+impl Date {
+    /// Returns `None` unless `month` and `day` name a real day of `year`.
+    pub const fn new(year: i32, month: u8, day: u8) -> Option<Date> {
+        if month < 1 || month > 12 || day < 1 || day > days_in_month(year, month) {
+            return None;
+        }
+        Some(Date { year, month, day })
+    }
+
+    pub const fn year(&self) -> i32 {
+        self.year
+    }
+
+    pub const fn month(&self) -> u8 {
+        self.month
+    }
+
+    pub const fn day(&self) -> u8 {
+        self.day
+    }
+
+    /// Days since `1970-01-01`, negative before it.
+    pub const fn days_since_epoch(&self) -> i64 {
+        // Howard Hinnant's days_from_civil.
+        let year = if self.month <= 2 {
+            self.year as i64 - 1
+        } else {
+            self.year as i64
+        };
+        let era = year.div_euclid(400);
+        let year_of_era = year - era * 400;
+        let month = self.month as i64;
+        let day_of_year =
+            (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + self.day as i64 - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+        era * 146_097 + day_of_era - 719_468
+    }
+
+    /// Inverse of [`Date::days_since_epoch`].
+    pub const fn from_days_since_epoch(days: i64) -> Date {
+        let days = days + 719_468;
+        let era = days.div_euclid(146_097);
+        let day_of_era = days - era * 146_097;
+        let year_of_era =
+            (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let shifted_month = (5 * day_of_year + 2) / 153;
+        let day = (day_of_year - (153 * shifted_month + 2) / 5 + 1) as u8;
+        let month = if shifted_month < 10 {
+            shifted_month + 3
+        } else {
+            shifted_month - 9
+        } as u8;
+        let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+        Date {
+            year: year as i32,
+            month,
+            day,
+        }
+    }
+
+    /// The date `days` later (earlier when negative).
+    pub const fn add_days(&self, days: i64) -> Date {
+        Date::from_days_since_epoch(self.days_since_epoch() + days)
+    }
+
+    /// Seconds since the Unix epoch at midnight UTC.
+    pub const fn unix_timestamp(&self) -> i64 {
+        self.days_since_epoch() * 86_400
+    }
+}
+
+const fn is_leap_year(year: i32) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+const fn days_in_month(year: i32, month: u8) -> u8 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Formats as ISO 8601 `YYYY-MM-DD`.
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+/// Parses ISO 8601 `YYYY-MM-DD`.
+impl FromStr for Date {
+    type Err = DateError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let bytes = s.as_bytes();
+        if bytes.len() != 10 || bytes[4] != b'-' || bytes[7] != b'-' {
+            return Err(DateError::Malformed);
+        }
+        let number = |range: core::ops::Range<usize>| {
+            let digits = &s[range];
+            if !digits.bytes().all(|b| b.is_ascii_digit()) {
+                return Err(DateError::Malformed);
+            }
+            digits.parse::<i32>().map_err(|_| DateError::Malformed)
+        };
+        let (year, month, day) = (number(0..4)?, number(5..7)?, number(8..10)?);
+        Date::new(year, month as u8, day as u8).ok_or(DateError::OutOfRange)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Date, DateError};
+
+    #[test]
+    fn test_days() {
+        for (s, days) in [
+            ("1970-01-01", 0),
+            ("1969-12-31", -1),
+            ("2000-02-29", 11_016),
+            ("1999-01-04", 10_595),
+            ("2025-10-17", 20_378),
+            ("0001-01-01", -719_162),
+        ] {
+            let date: Date = s.parse().unwrap();
+            assert_eq!(days, date.days_since_epoch(), "{}", s);
+            assert_eq!(date, Date::from_days_since_epoch(days));
+            assert_eq!(s, date.to_string());
+        }
+        let mut date = Date::new(1899, 12, 31).unwrap();
+        for _ in 0..200_000 {
+            let next = date.add_days(1);
+            assert!(next > date);
+            assert_eq!(Some(next), Date::new(next.year(), next.month(), next.day()));
+            date = next;
+        }
+        assert_eq!(86_400, Date::new(1970, 1, 2).unwrap().unix_timestamp());
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(Err(DateError::OutOfRange), "2023-02-29".parse::<Date>());
+        assert_eq!(Err(DateError::OutOfRange), "2024-13-01".parse::<Date>());
+        assert!("2024-02-29".parse::<Date>().is_ok());
+        for s in ["2024-1-01", "2024/01/01", "24-01-01", "+024-01-01", ""] {
+            assert_eq!(Err(DateError::Malformed), s.parse::<Date>(), "{:?}", s);
+        }
+    }
+}
@@ -0,0 +1,443 @@
+use core::cmp::Ordering;
+use core::fmt;
+use core::str::FromStr;
+
+use crate::{CurrencyCode, Money, MoneyError};
+
+/// Exact signed decimal `mantissa × 10^-scale`, e.g. `Decimal::new(12345, 3)`
+/// is `12.345`.
+///
+/// Equality is structural: `1.0` and `1.00` differ.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Decimal {
+    mantissa: i128,
+    scale: u32,
+}
+
+/// How to drop the digits a currency's minor unit cannot hold.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum RoundingMode {
+    /// Nearest, ties away from zero (`2.5` → `3`, `-2.5` → `-3`).
+    HalfUp,
+    /// Nearest, ties to the even neighbour (banker's rounding).
+    HalfEven,
+    /// Nearest, ties toward zero (`2.5` → `2`, `-2.5` → `-2`).
+    HalfDown,
+    /// Toward positive infinity.
+    Ceiling,
+    /// Toward negative infinity.
+    Floor,
+    /// Truncation (`2.9` → `2`, `-2.9` → `-2`).
+    TowardZero,
+}
+
+/// A rounded value and what rounding dropped: `original = value + remainder`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Rounded<T> {
+    pub value: T,
+    /// Difference at the original scale, to be posted separately.
+    pub remainder: Decimal,
+}
+
+impl RoundingMode {
+    /// Snake-case name, e.g. `"half_even"`.
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            RoundingMode::HalfUp => "half_up",
+            RoundingMode::HalfEven => "half_even",
+            RoundingMode::HalfDown => "half_down",
+            RoundingMode::Ceiling => "ceiling",
+            RoundingMode::Floor => "floor",
+            RoundingMode::TowardZero => "toward_zero",
+        }
+    }
+}
+
+impl fmt::Display for RoundingMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Decimal {
+    pub const ZERO: Decimal = Decimal::new(0, 0);
+
+    pub const fn new(mantissa: i128, scale: u32) -> Decimal {
+        Decimal { mantissa, scale }
+    }
+
+    pub const fn mantissa(&self) -> i128 {
+        self.mantissa
+    }
+
+    /// Number of digits after the decimal point.
+    pub const fn scale(&self) -> u32 {
+        self.scale
+    }
+
+    pub const fn is_zero(&self) -> bool {
+        self.mantissa == 0
+    }
+
+    /// Mantissa at `scale` decimals; `None` when non-zero digits would be lost
+    /// or the result overflows.
+    pub fn rescale(&self, scale: u32) -> Option<i128> {
+        if self.scale <= scale {
+            self.mantissa
+                .checked_mul(10_i128.checked_pow(scale - self.scale)?)
+        } else {
+            match 10_i128.checked_pow(self.scale - scale) {
+                Some(divisor) => (self.mantissa % divisor == 0).then(|| self.mantissa / divisor),
+                // More than 38 dropped digits: only zero survives.
+                None => (self.mantissa == 0).then_some(0),
+            }
+        }
+    }
+
+    /// Exact sum at the larger of both scales; `None` on overflow.
+    pub fn checked_add(self, rhs: Decimal) -> Option<Decimal> {
+        let scale = self.scale.max(rhs.scale);
+        let mantissa = self.rescale(scale)?.checked_add(rhs.rescale(scale)?)?;
+        Some(Decimal::new(mantissa, scale))
+    }
+
+    /// Exact difference at the larger of both scales; `None` on overflow.
+    pub fn checked_sub(self, rhs: Decimal) -> Option<Decimal> {
+        self.checked_add(Decimal::new(rhs.mantissa.checked_neg()?, rhs.scale))
+    }
+
+    /// Exact product; `None` on overflow.
+    pub fn checked_mul(self, rhs: Decimal) -> Option<Decimal> {
+        let mantissa = self.mantissa.checked_mul(rhs.mantissa)?;
+        Some(Decimal::new(mantissa, self.scale.checked_add(rhs.scale)?))
+    }
+
+    /// Rounds to `scale` decimals; `None` when the result overflows.
+    /// #Sample
+    /// ```
+    /// use rust_iso4217::{Decimal, RoundingMode};
+    ///
+    /// let tax: Decimal = "2.675".parse().unwrap();
+    /// let rounded = tax.round(2, RoundingMode::HalfEven).unwrap();
+    /// assert_eq!("2.68", rounded.value.to_string());
+    /// assert_eq!("-0.005", rounded.remainder.to_string());
+    /// ```
+    pub fn round(&self, scale: u32, mode: RoundingMode) -> Option<Rounded<Decimal>> {
+        if self.scale <= scale {
+            return Some(Rounded {
+                value: Decimal::new(self.rescale(scale)?, scale),
+                remainder: Decimal::new(0, self.scale),
+            });
+        }
+        let divisor = match 10_i128.checked_pow(self.scale - scale) {
+            Some(divisor) => divisor,
+            // Every digit is dropped; only the direction is left to decide.
+            None => return self.round_all(scale, mode),
+        };
+        let value = divide(self.mantissa, divisor, mode)?;
+        let remainder = self.mantissa - value.checked_mul(divisor)?;
+        Some(Rounded {
+            value: Decimal::new(value, scale),
+            remainder: Decimal::new(remainder, self.scale),
+        })
+    }
+
+    /// Quotient rounded to `scale` decimals; `None` when `rhs` is zero or the
+    /// result overflows.
+    /// #Sample
+    /// ```
+    /// use rust_iso4217::{Decimal, RoundingMode};
+    ///
+    /// let third = Decimal::new(1, 0).checked_div(Decimal::new(3, 0), 4, RoundingMode::HalfEven);
+    /// assert_eq!(Some(Decimal::new(3333, 4)), third);
+    /// ```
+    pub fn checked_div(self, rhs: Decimal, scale: u32, mode: RoundingMode) -> Option<Decimal> {
+        // self / rhs = (m1 / m2) * 10^(s2 - s1), wanted at 10^-scale.
+        let shift = scale as i64 + rhs.scale as i64 - self.scale as i64;
+        let pow = 10_i128.checked_pow(shift.unsigned_abs().try_into().ok()?)?;
+        let (numerator, denominator) = if shift >= 0 {
+            (self.mantissa.checked_mul(pow)?, rhs.mantissa)
+        } else {
+            (self.mantissa, rhs.mantissa.checked_mul(pow)?)
+        };
+        Some(Decimal::new(divide(numerator, denominator, mode)?, scale))
+    }
+
+    fn round_all(&self, scale: u32, mode: RoundingMode) -> Option<Rounded<Decimal>> {
+        let value = match mode {
+            RoundingMode::Ceiling if self.mantissa > 0 => 1,
+            RoundingMode::Floor if self.mantissa < 0 => -1,
+            _ => 0,
+        };
+        if value != 0 {
+            // A single unit at `scale` cannot be expressed at `self.scale`.
+            return None;
+        }
+        Some(Rounded {
+            value: Decimal::new(0, scale),
+            remainder: *self,
+        })
+    }
+}
+
+/// `numerator / denominator` rounded to an integer with `mode`.
+fn divide(numerator: i128, denominator: i128, mode: RoundingMode) -> Option<i128> {
+    let quotient = numerator.checked_div(denominator)?;
+    let rest = numerator % denominator;
+    if rest == 0 {
+        return Some(quotient);
+    }
+    let negative = (numerator < 0) != (denominator < 0);
+    let away = if negative { quotient - 1 } else { quotient + 1 };
+    let half = rest
+        .unsigned_abs()
+        .cmp(&(denominator.unsigned_abs() - rest.unsigned_abs()));
+    Some(match (mode, half) {
+        (RoundingMode::TowardZero, _) => quotient,
+        (RoundingMode::Floor, _) if negative => away,
+        (RoundingMode::Floor, _) => quotient,
+        (RoundingMode::Ceiling, _) if !negative => away,
+        (RoundingMode::Ceiling, _) => quotient,
+        (_, Ordering::Greater) => away,
+        (_, Ordering::Less) => quotient,
+        (RoundingMode::HalfUp, _) => away,
+        (RoundingMode::HalfDown, _) => quotient,
+        (RoundingMode::HalfEven, _) if quotient % 2 == 0 => quotient,
+        (RoundingMode::HalfEven, _) => away,
+    })
+}
+
+impl Default for Decimal {
+    fn default() -> Self {
+        Decimal::ZERO
+    }
+}
+
+/// Parses `[+-]digits[.digits]`, e.g. `"-0.125"`.
+impl FromStr for Decimal {
+    type Err = MoneyError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (negative, digits) = match s.as_bytes().first() {
+            Some(b'-') => (true, &s[1..]),
+            Some(b'+') => (false, &s[1..]),
+            _ => (false, s),
+        };
+        let (int, frac) = digits.split_once('.').unwrap_or((digits, ""));
+        if int.is_empty() || (digits.contains('.') && frac.is_empty()) {
+            return Err(MoneyError::InvalidAmount);
+        }
+        let mut mantissa: i128 = 0;
+        for b in int.bytes().chain(frac.bytes()) {
+            if !b.is_ascii_digit() {
+                return Err(MoneyError::InvalidAmount);
+            }
+            mantissa = mantissa
+                .checked_mul(10)
+                .and_then(|m| m.checked_add((b - b'0') as i128))
+                .ok_or(MoneyError::Overflow)?;
+        }
+        Ok(Decimal {
+            mantissa: if negative { -mantissa } else { mantissa },
+            scale: frac.len() as u32,
+        })
+    }
+}
+
+impl fmt::Display for Decimal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // i128::MIN has 39 digits.
+        let mut digits = [b'0'; 39];
+        let mut abs = self.mantissa.unsigned_abs();
+        let mut len = 0;
+        while abs > 0 {
+            digits[digits.len() - 1 - len] = b'0' + (abs % 10) as u8;
+            abs /= 10;
+            len += 1;
+        }
+        let digits = &digits[digits.len() - len..];
+        if self.mantissa < 0 {
+            f.write_str("-")?;
+        }
+        let scale = self.scale as usize;
+        if scale == 0 && digits.is_empty() {
+            f.write_str("0")?;
+        } else if digits.len() > scale {
+            let (int, frac) = digits.split_at(digits.len() - scale);
+            f.write_str(ascii(int))?;
+            if !frac.is_empty() {
+                write!(f, ".{}", ascii(frac))?;
+            }
+        } else {
+            f.write_str("0.")?;
+            for _ in digits.len()..scale {
+                f.write_str("0")?;
+            }
+            f.write_str(ascii(digits))?;
+        }
+        Ok(())
+    }
+}
+
+fn ascii(digits: &[u8]) -> &str {
+    core::str::from_utf8(digits).unwrap_or_default()
+}
+
+impl CurrencyCode {
+    /// Rounds `amount` to this currency's minor unit.
+    /// #Sample
+    /// ```
+    /// use rust_iso4217::{Decimal, RoundingMode};
+    ///
+    /// let interest = Decimal::new(1_234_567, 4); // 123.4567
+    /// let rounded = rust_iso4217::KWD.round(interest, RoundingMode::HalfUp).unwrap();
+    /// assert_eq!("123.457 KWD", rounded.value.to_string());
+    /// assert_eq!("-0.0003", rounded.remainder.to_string());
+    /// ```
+    pub fn round(&self, amount: Decimal, mode: RoundingMode) -> Result<Rounded<Money>, MoneyError> {
+        let unit = self.minor_unit().ok_or(MoneyError::NoMinorUnit)?;
+        let rounded = amount
+            .round(unit as u32, mode)
+            .ok_or(MoneyError::Overflow)?;
+        let minor = i64::try_from(rounded.value.mantissa).map_err(|_| MoneyError::Overflow)?;
+        Ok(Rounded {
+            value: Money::from_minor(minor, *self)?,
+            remainder: rounded.remainder,
+        })
+    }
+
+    /// Rounds the integer pair `mantissa × 10^-scale` to this currency's
+    /// minor unit.
+    pub fn round_mantissa(
+        &self,
+        mantissa: i128,
+        scale: u32,
+        mode: RoundingMode,
+    ) -> Result<Rounded<Money>, MoneyError> {
+        self.round(Decimal::new(mantissa, scale), mode)
+    }
+}
+
+impl Money {
+    /// Parses a decimal amount of any precision and rounds it to the minor
+    /// unit of `currency`.
+    pub fn from_decimal_str_rounded(
+        s: &str,
+        currency: CurrencyCode,
+        mode: RoundingMode,
+    ) -> Result<Rounded<Money>, MoneyError> {
+        currency.minor_unit().ok_or(MoneyError::NoMinorUnit)?;
+        currency.round(s.parse()?, mode)
+    }
+
+    /// The exact amount as a decimal at the currency's minor unit.
+    pub fn to_decimal(&self) -> Decimal {
+        Decimal::new(self.amount() as i128, self.minor_unit() as u32)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Decimal, Money, MoneyError, RoundingMode};
+
+    const MODES: [RoundingMode; 6] = [
+        RoundingMode::HalfUp,
+        RoundingMode::HalfEven,
+        RoundingMode::HalfDown,
+        RoundingMode::Ceiling,
+        RoundingMode::Floor,
+        RoundingMode::TowardZero,
+    ];
+
+    #[test]
+    fn test_rounding_table() {
+        // input, then HalfUp, HalfEven, HalfDown, Ceiling, Floor, TowardZero
+        let table = [
+            ("5.5", [6, 6, 5, 6, 5, 5]),
+            ("2.5", [3, 2, 2, 3, 2, 2]),
+            ("1.6", [2, 2, 2, 2, 1, 1]),
+            ("1.1", [1, 1, 1, 2, 1, 1]),
+            ("1.0", [1, 1, 1, 1, 1, 1]),
+            ("-1.0", [-1, -1, -1, -1, -1, -1]),
+            ("-1.1", [-1, -1, -1, -1, -2, -1]),
+            ("-1.6", [-2, -2, -2, -1, -2, -1]),
+            ("-2.5", [-3, -2, -2, -2, -3, -2]),
+            ("-5.5", [-6, -6, -5, -5, -6, -5]),
+        ];
+        for (input, expected) in table {
+            let decimal: Decimal = input.parse().unwrap();
+            for (mode, want) in MODES.iter().zip(expected) {
+                let rounded = decimal.round(0, *mode).unwrap();
+                assert_eq!(want, rounded.value.mantissa(), "{} {:?}", input, mode);
+                assert_eq!(
+                    decimal.mantissa(),
+                    rounded.value.mantissa() * 10 + rounded.remainder.mantissa()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_currency_units() {
+        let amount: Decimal = "1234.56789".parse().unwrap();
+        for (code, want) in [
+            (crate::JPY, "1235 JPY"),
+            (crate::USD, "1234.57 USD"),
+            (crate::KWD, "1234.568 KWD"),
+            (crate::CLF, "1234.5679 CLF"),
+        ] {
+            let rounded = code.round(amount, RoundingMode::HalfEven).unwrap();
+            assert_eq!(want, rounded.value.to_string());
+        }
+        let rounded = Money::from_decimal_str_rounded("0.125", crate::EUR, RoundingMode::HalfEven);
+        let rounded = rounded.unwrap();
+        assert_eq!(12, rounded.value.amount());
+        assert_eq!(Decimal::new(5, 3), rounded.remainder);
+        assert_eq!(
+            Err(MoneyError::NoMinorUnit),
+            crate::XAU.round(amount, RoundingMode::Floor)
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        for s in [
+            "0",
+            "-0.05",
+            "12.340",
+            "0.000001",
+            "-170141183460469231731687303715884105727",
+        ] {
+            assert_eq!(s, s.parse::<Decimal>().unwrap().to_string());
+        }
+        assert_eq!("0.0", Decimal::new(0, 1).to_string());
+        let a = Decimal::new(15, 1);
+        assert_eq!(
+            Some(Decimal::new(1525, 3)),
+            a.checked_add(Decimal::new(25, 3))
+        );
+        assert_eq!(
+            Some(Decimal::new(375, 4)),
+            a.checked_mul(Decimal::new(25, 3))
+        );
+        assert_eq!(None, Decimal::new(i128::MAX, 0).checked_add(a));
+        assert_eq!(
+            Some(Decimal::new(1475, 3)),
+            a.checked_sub(Decimal::new(25, 3))
+        );
+        let d = |m, s, mode| Decimal::new(-2, 0).checked_div(Decimal::new(m, s), 2, mode);
+        assert_eq!(Some(Decimal::new(-67, 2)), d(3, 0, RoundingMode::HalfUp));
+        assert_eq!(Some(Decimal::new(-66, 2)), d(3, 0, RoundingMode::Ceiling));
+        assert_eq!(Some(Decimal::new(-13334, 2)), d(15, 3, RoundingMode::Floor));
+        assert_eq!(
+            Some(Decimal::new(-2, 2)),
+            d(10000, 2, RoundingMode::HalfEven)
+        );
+        assert_eq!(None, d(0, 0, RoundingMode::HalfEven));
+        assert_eq!(
+            "-1.70141183460469231731687303715884105728",
+            Decimal::new(i128::MIN, 38).to_string()
+        );
+        assert_eq!(Err(MoneyError::Overflow), "1".repeat(40).parse::<Decimal>());
+    }
+}
@@ -0,0 +1,254 @@
+use core::fmt;
+
+/// Error returned by [`try_from_country`](crate::try_from_country) when a
+/// country has no currency to offer.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum CountryError {
+    /// The input is not a three letter ISO 3166-1 alpha-3 code.
+    Malformed,
+    /// The input is well formed but not a known ISO 3166-1 alpha-3 code.
+    UnknownCountry,
+    /// The country exists but has no ISO 4217 currency (e.g. `ATA`).
+    NoCurrency,
+}
+
+impl fmt::Display for CountryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CountryError::Malformed => f.write_str("country code must be 3 ASCII letters"),
+            CountryError::UnknownCountry => f.write_str("unknown ISO 3166-1 alpha-3 country code"),
+            CountryError::NoCurrency => f.write_str("country has no ISO 4217 currency"),
+        }
+    }
+}
+
+impl core::error::Error for CountryError {}
+
+/// Error returned when a string or number cannot be parsed into a
+/// [`CurrencyCode`](crate::CurrencyCode).
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum ParseCurrencyError {
+    /// The input is not 3 characters long; holds the length found.
+    WrongLength(usize),
+    /// The input contains non-ASCII characters.
+    NonAscii,
+    /// No ISO 4217 alpha code matches; holds the closest known code, if any.
+    UnknownCode { suggestion: Option<&'static str> },
+    /// The numeric code is in range but unassigned.
+    UnknownNumeric(u16),
+    /// The numeric code is outside `0..=999`.
+    OutOfRange(i32),
+}
+
+impl fmt::Display for ParseCurrencyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseCurrencyError::WrongLength(len) => {
+                write!(f, "currency code must be 3 characters long, found {}", len)
+            }
+            ParseCurrencyError::NonAscii => f.write_str("currency code must be ASCII"),
+            ParseCurrencyError::UnknownCode {
+                suggestion: Some(code),
+            } => {
+                write!(f, "unknown ISO 4217 alpha code, did you mean {}?", code)
+            }
+            ParseCurrencyError::UnknownCode { suggestion: None } => {
+                f.write_str("unknown ISO 4217 alpha code")
+            }
+            ParseCurrencyError::UnknownNumeric(numeric) => {
+                write!(f, "unknown ISO 4217 numeric code {:03}", numeric)
+            }
+            ParseCurrencyError::OutOfRange(numeric) => {
+                write!(f, "numeric code {} is outside 0..=999", numeric)
+            }
+        }
+    }
+}
+
+impl core::error::Error for ParseCurrencyError {}
+
+/// Error returned when converting amounts between major and minor units.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum MinorUnitError {
+    /// The currency has no minor unit (`N.A.`, e.g. `XAU` or historic codes).
+    NoMinorUnit,
+    /// The converted amount does not fit in an `i64`.
+    Overflow,
+}
+
+impl fmt::Display for MinorUnitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MinorUnitError::NoMinorUnit => f.write_str("currency has no minor unit"),
+            MinorUnitError::Overflow => f.write_str("amount overflows i64"),
+        }
+    }
+}
+
+impl core::error::Error for MinorUnitError {}
+
+/// Error returned by [`Money`](crate::Money) construction and arithmetic.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum MoneyError {
+    /// The currency has no minor unit (`N.A.`), so amounts cannot be held.
+    NoMinorUnit,
+    /// The operands are in different currencies; holds their alpha codes.
+    CurrencyMismatch(&'static str, &'static str),
+    /// The result does not fit in an `i64` of minor units.
+    Overflow,
+    /// The decimal amount is malformed.
+    InvalidAmount,
+    /// The decimal amount has more decimals than the currency's minor unit.
+    TooManyDecimals,
+    /// No exchange rate is known from the first to the second alpha code.
+    MissingRate(&'static str, &'static str),
+    /// Allocation ratios are empty or all zero, or a split count is zero or
+    /// exceeds `i64::MAX`.
+    InvalidRatios,
+    /// The currency part of a `"12.34 USD"` string is not a known code.
+    Currency(ParseCurrencyError),
+    /// The exchange rates could not be found or composed.
+    Rate(RateError),
+    /// The historic code has no replacement to restate amounts in.
+    NoSuccessor(&'static str),
+}
+
+impl fmt::Display for MoneyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MoneyError::NoMinorUnit => f.write_str("currency has no minor unit"),
+            MoneyError::CurrencyMismatch(a, b) => write!(f, "currency mismatch: {} and {}", a, b),
+            MoneyError::Overflow => f.write_str("amount overflows i64 minor units"),
+            MoneyError::InvalidAmount => f.write_str("invalid decimal amount"),
+            MoneyError::TooManyDecimals => {
+                f.write_str("amount has more decimals than the currency's minor unit")
+            }
+            MoneyError::MissingRate(from, to) => {
+                write!(f, "no exchange rate from {} to {}", from, to)
+            }
+            MoneyError::InvalidRatios => {
+                f.write_str("allocation ratios or share count are invalid")
+            }
+            MoneyError::Currency(err) => err.fmt(f),
+            MoneyError::Rate(err) => err.fmt(f),
+            MoneyError::NoSuccessor(code) => write!(f, "{} has no current successor", code),
+        }
+    }
+}
+
+impl core::error::Error for MoneyError {}
+
+impl From<ParseCurrencyError> for MoneyError {
+    fn from(err: ParseCurrencyError) -> Self {
+        MoneyError::Currency(err)
+    }
+}
+
+impl From<RateError> for MoneyError {
+    fn from(err: RateError) -> Self {
+        MoneyError::Rate(err)
+    }
+}
+
+/// Error returned by [`ExchangeRate`](crate::ExchangeRate) construction and
+/// composition.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum RateError {
+    /// The rate is zero or negative.
+    NonPositive,
+    /// A cross rate needs the first quote to be the second base; holds both.
+    Unchained(&'static str, &'static str),
+    /// The composed or inverted rate does not fit in a `Decimal`.
+    Overflow,
+    /// No chain of quoted rates links the first code to the second.
+    NoPath(&'static str, &'static str),
+    /// A rate from a currency to itself other than 1.
+    SameCurrency(&'static str),
+    /// A currency is not in the ISO 4217 table.
+    Currency(ParseCurrencyError),
+}
+
+impl fmt::Display for RateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RateError::NonPositive => f.write_str("exchange rate must be positive"),
+            RateError::Unchained(quote, base) => {
+                write!(
+                    f,
+                    "cannot chain a rate quoted in {} with one based in {}",
+                    quote, base
+                )
+            }
+            RateError::Overflow => f.write_str("exchange rate overflows"),
+            RateError::NoPath(from, to) => write!(f, "no rate path from {} to {}", from, to),
+            RateError::SameCurrency(code) => write!(f, "a {0}/{0} rate must be 1", code),
+            RateError::Currency(err) => err.fmt(f),
+        }
+    }
+}
+
+impl core::error::Error for RateError {}
+
+impl From<ParseCurrencyError> for RateError {
+    fn from(err: ParseCurrencyError) -> Self {
+        RateError::Currency(err)
+    }
+}
+
+/// Error returned when parsing a [`Date`](crate::Date).
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum DateError {
+    /// The input is not `YYYY-MM-DD`.
+    Malformed,
+    /// The month or day does not exist, e.g. `2023-02-29`.
+    OutOfRange,
+}
+
+impl fmt::Display for DateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DateError::Malformed => f.write_str("date must be YYYY-MM-DD"),
+            DateError::OutOfRange => f.write_str("date does not exist"),
+        }
+    }
+}
+
+impl core::error::Error for DateError {}
+
+/// Error returned when loading an offline rate file. Line numbers start at 1.
+///
+/// Non-exhaustive: `Io` only exists with the `std` feature.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum LoadError {
+    /// The line does not have the expected shape.
+    Malformed { line: usize },
+    /// The date on the line cannot be parsed.
+    InvalidDate { line: usize },
+    /// The rate on the line is not a positive decimal.
+    InvalidRate { line: usize },
+    /// The file cannot be read.
+    #[cfg(feature = "std")]
+    Io(std::io::ErrorKind),
+}
+
+impl fmt::Display for LoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LoadError::Malformed { line } => write!(f, "line {}: malformed rate record", line),
+            LoadError::InvalidDate { line } => write!(f, "line {}: invalid date", line),
+            LoadError::InvalidRate { line } => write!(f, "line {}: invalid rate", line),
+            #[cfg(feature = "std")]
+            LoadError::Io(kind) => write!(f, "cannot read rate file: {}", kind),
+        }
+    }
+}
+
+impl core::error::Error for LoadError {}
+
+#[cfg(feature = "std")]
+impl From<std::io::Error> for LoadError {
+    fn from(err: std::io::Error) -> Self {
+        LoadError::Io(err.kind())
+    }
+}
@@ -0,0 +1,194 @@
+use crate::{Currency, CurrencyCode, Date, Decimal, MoneyError, RoundingMode};
+
+/// Decimals kept for the euro amount when converting between two legacy
+/// currencies; the regulation requires at least three.
+const TRIANGULATION_SCALE: u32 = 3;
+
+/// Currencies replaced by the euro at an irrevocably fixed rate, with the
+/// decimals their amounts were kept to.
+const EURO_LEGACY: &[(Currency, u32)] = &[
+    (Currency::ATS, 2),
+    (Currency::BEF, 0),
+    (Currency::DEM, 2),
+    (Currency::ESP, 0),
+    (Currency::FIM, 2),
+    (Currency::FRF, 2),
+    (Currency::IEP, 2),
+    (Currency::ITL, 0),
+    (Currency::LUF, 0),
+    (Currency::NLG, 2),
+    (Currency::PTE, 0),
+    (Currency::GRD, 0),
+    (Currency::SIT, 2),
+    (Currency::CYP, 2),
+    (Currency::MTL, 2),
+    (Currency::SKK, 2),
+    (Currency::EEK, 2),
+    (Currency::LVL, 2),
+    (Currency::LTL, 2),
+    (Currency::HRK, 2),
+    (Currency::BGN, 2),
+];
+
+impl Currency {
+    /// Units of this currency per euro, fixed on euro adoption as six
+    /// significant figures; `None` unless this is a euro legacy currency.
+    /// #Sample
+    /// ```
+    /// use rust_iso4217::{Currency, Date, Decimal};
+    ///
+    /// assert_eq!(Some(Decimal::new(195_583, 5)), Currency::DEM.euro_rate());
+    /// assert_eq!(Date::new(2023, 1, 1), Currency::HRK.euro_adoption());
+    /// assert_eq!(None, Currency::GBP.euro_rate());
+    /// ```
+    pub fn euro_rate(self) -> Option<Decimal> {
+        legacy_unit(self)?;
+        Some(self.replacement()?.ratio?.0)
+    }
+
+    /// Day the euro replaced this legacy currency.
+    pub fn euro_adoption(self) -> Option<Date> {
+        legacy_unit(self)?;
+        Some(self.replacement()?.effective)
+    }
+}
+
+impl CurrencyCode {
+    /// See [`Currency::euro_rate`].
+    pub fn euro_rate(&self) -> Option<Decimal> {
+        self.currency()?.euro_rate()
+    }
+
+    /// See [`Currency::euro_adoption`].
+    pub fn euro_adoption(&self) -> Option<Date> {
+        self.currency()?.euro_adoption()
+    }
+}
+
+/// Decimals of a euro legacy currency's amounts.
+fn legacy_unit(currency: Currency) -> Option<u32> {
+    EURO_LEGACY
+        .iter()
+        .find(|(legacy, _)| *legacy == currency)
+        .map(|(_, unit)| *unit)
+}
+
+/// Converts `amount` between `EUR` and its legacy currencies as Council
+/// Regulation (EC) No 1103/97 requires.
+///
+/// Only the fixed rates are used, never their inverses: legacy amounts are
+/// divided by their rate and `EUR` amounts multiplied by it. Between two
+/// legacy currencies the amount goes through `EUR` rounded to three
+/// decimals. Results are rounded half up to the target's decimals (cents
+/// for `EUR`, pfennigs for `DEM`, whole lire for `ITL`).
+/// #Sample
+/// ```
+/// use rust_iso4217::{euro_convert, Decimal};
+///
+/// let marks = Decimal::new(100, 0);
+/// assert_eq!(Ok(Decimal::new(5113, 2)), euro_convert(marks, rust_iso4217::DEM, rust_iso4217::EUR));
+/// // 100 DEM = 51.129 EUR = 335.38 FRF
+/// assert_eq!(Ok(Decimal::new(33538, 2)), euro_convert(marks, rust_iso4217::DEM, rust_iso4217::FRF));
+/// ```
+pub fn euro_convert(
+    amount: Decimal,
+    from: CurrencyCode,
+    to: CurrencyCode,
+) -> Result<Decimal, MoneyError> {
+    let missing = MoneyError::MissingRate(from.code, to.code);
+    let (from_currency, to_currency) = (Currency::try_from(from)?, Currency::try_from(to)?);
+    let round = |amount: Decimal, scale| {
+        amount
+            .round(scale, RoundingMode::HalfUp)
+            .map(|rounded| rounded.value)
+            .ok_or(MoneyError::Overflow)
+    };
+    if from_currency == to_currency && from_currency != Currency::EUR {
+        return round(amount, legacy_unit(from_currency).ok_or(missing)?);
+    }
+    let euros = if from_currency == Currency::EUR {
+        amount
+    } else {
+        let rate = from_currency.euro_rate().ok_or(missing)?;
+        let scale = if to_currency == Currency::EUR {
+            2
+        } else {
+            TRIANGULATION_SCALE
+        };
+        amount
+            .checked_div(rate, scale, RoundingMode::HalfUp)
+            .ok_or(MoneyError::Overflow)?
+    };
+    if to_currency == Currency::EUR {
+        return round(euros, 2);
+    }
+    let (rate, unit) = to_currency
+        .euro_rate()
+        .zip(legacy_unit(to_currency))
+        .ok_or(missing)?;
+    round(euros.checked_mul(rate).ok_or(MoneyError::Overflow)?, unit)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{euro_convert, Currency, Date, Decimal, MoneyError};
+
+    #[test]
+    fn test_euro_rates() {
+        let adopted = |currency: Currency| currency.euro_adoption().unwrap();
+        assert_eq!(
+            21,
+            Currency::EUR
+                .predecessors()
+                .iter()
+                .filter(|c| c.euro_rate().is_some())
+                .count()
+        );
+        assert_eq!(Date::new(1999, 1, 1), Some(adopted(Currency::ITL)));
+        assert_eq!(Date::new(2001, 1, 1), Some(adopted(Currency::GRD)));
+        assert_eq!(Date::new(2026, 1, 1), Some(adopted(Currency::BGN)));
+        assert_eq!(Some(Decimal::new(193_627, 2)), Currency::ITL.euro_rate());
+        assert_eq!(Some(Decimal::new(753_450, 5)), crate::HRK.euro_rate());
+        // Not a legacy currency, though it was replaced by the euro.
+        assert_eq!(None, Currency::ADP.euro_rate());
+        assert_eq!(None, Currency::XEU.euro_rate());
+    }
+
+    #[test]
+    fn test_euro_convert() {
+        let convert = |amount: &str, from, to| {
+            euro_convert(amount.parse().unwrap(), from, to).map(|d| d.to_string())
+        };
+        assert_eq!(Ok("6.56".to_string()), convert("1", crate::EUR, crate::FRF));
+        assert_eq!(Ok("1936".to_string()), convert("1", crate::EUR, crate::ITL));
+        assert_eq!(
+            Ok("0.01".to_string()),
+            convert("10", crate::ITL, crate::EUR)
+        );
+        // 1000 ITL = 0.516 EUR = 1.01 DEM
+        assert_eq!(
+            Ok("1.01".to_string()),
+            convert("1000", crate::ITL, crate::DEM)
+        );
+        assert_eq!(
+            Ok("-2.56".to_string()),
+            convert("-5", crate::DEM, crate::EUR)
+        );
+        assert_eq!(
+            Ok("99.99".to_string()),
+            convert("99.994", crate::DEM, crate::DEM)
+        );
+        assert_eq!(
+            Ok("12.35".to_string()),
+            convert("12.345", crate::EUR, crate::EUR)
+        );
+        assert_eq!(
+            Err(MoneyError::MissingRate("DEM", "USD")),
+            convert("1", crate::DEM, crate::USD)
+        );
+        assert_eq!(
+            Err(MoneyError::MissingRate("GBP", "EUR")),
+            convert("1", crate::GBP, crate::EUR)
+        );
+    }
+}
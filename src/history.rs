@@ -0,0 +1,1054 @@
+use crate::{
+    Currency, CurrencyCode, CurrencySet, Date, Decimal, Money, MoneyError, RoundingMode,
+    WITHDRAWALS,
+};
+
+/// A withdrawal of a code by one entity, as listed in ISO 4217 list three.
+///
+/// A code can be withdrawn several times: by several countries (`FRF`,
+/// `RUR`), or again after a change of name or reuse (`ZWD` in 2006 and 2008).
+/// #Sample
+/// ```
+/// use rust_iso4217::{Currency, Date};
+///
+/// let withdrawals = Currency::ZWD.withdrawals();
+/// assert_eq!(2, withdrawals.len());
+/// assert_eq!("ZIMBABWE", withdrawals[1].entity);
+/// assert_eq!(Date::new(2008, 8, 31), Some(withdrawals[1].until));
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Withdrawal {
+    pub currency: Currency,
+    /// Country or entity as spelled in the list, e.g. `"GERMANY"`.
+    pub entity: &'static str,
+    /// First day of the withdrawal period.
+    pub from: Date,
+    /// Last day of the withdrawal period: the end of the month the list
+    /// gives, or of the last month of a range such as `1989 to 1990`.
+    pub until: Date,
+}
+
+impl Withdrawal {
+    /// Withdrawal over the months `first` to `last`, both `(year, month)`.
+    pub(crate) const fn new(
+        currency: Currency,
+        entity: &'static str,
+        first: (i32, u8),
+        last: (i32, u8),
+    ) -> Withdrawal {
+        let (Some(from), Some(after)) = (
+            Date::new(first.0, first.1, 1),
+            Date::new(last.0 + last.1 as i32 / 12, last.1 % 12 + 1, 1),
+        ) else {
+            panic!("invalid withdrawal month");
+        };
+        Withdrawal {
+            currency,
+            entity,
+            from,
+            until: after.add_days(-1),
+        }
+    }
+}
+
+impl Currency {
+    /// Withdrawals of this code, oldest first; empty for codes never
+    /// withdrawn anywhere.
+    ///
+    /// Active codes can have some too, e.g. `EUR` for Serbia and Montenegro
+    /// or `PEN` on its renaming.
+    pub fn withdrawals(self) -> &'static [Withdrawal] {
+        let start = WITHDRAWALS.partition_point(|w| w.currency < self);
+        let end = WITHDRAWALS.partition_point(|w| w.currency <= self);
+        &WITHDRAWALS[start..end]
+    }
+
+    /// See [`CurrencyCode::is_valid_on`].
+    pub fn is_valid_on(self, date: Date) -> bool {
+        self.introduced()
+            .is_none_or(|introduced| introduced <= date)
+            && (!self.is_historic()
+                || self
+                    .withdrawals()
+                    .iter()
+                    .any(|withdrawal| date <= withdrawal.until))
+    }
+}
+
+impl CurrencyCode {
+    /// See [`Currency::withdrawals`].
+    pub fn withdrawals(&self) -> &'static [Withdrawal] {
+        self.currency().map_or(&[], Currency::withdrawals)
+    }
+
+    /// Whether the code could be in use on `date`.
+    ///
+    /// Codes are from the day a replacement [introduced](Currency::introduced)
+    /// them; list three has no other introduction dates, so without one any
+    /// earlier date counts. Historic codes are until the end of their last
+    /// withdrawal period, active ones for good.
+    /// #Sample
+    /// ```
+    /// use rust_iso4217::Date;
+    ///
+    /// let date = Date::new(1998, 6, 30).unwrap();
+    /// assert!(rust_iso4217::DEM.is_valid_on(date));
+    /// assert!(!rust_iso4217::DEM.is_valid_on(Date::new(2002, 4, 1).unwrap()));
+    /// assert!(!rust_iso4217::EUR.is_valid_on(date));
+    /// assert!(rust_iso4217::USD.is_valid_on(date));
+    /// ```
+    pub fn is_valid_on(&self, date: Date) -> bool {
+        self.currency()
+            .is_some_and(|currency| currency.is_valid_on(date))
+    }
+}
+
+/// A historic code's replacement, and the ratio amounts were restated at.
+/// #Sample
+/// ```
+/// use rust_iso4217::{Currency, Date, Decimal};
+///
+/// let lira = Currency::TRL.replacement().unwrap();
+/// assert_eq!(Currency::TRY, lira.new);
+/// assert_eq!(Date::new(2005, 1, 1), Some(lira.effective));
+/// assert_eq!(Some((Decimal::new(1_000_000, 0), Decimal::new(1, 0))), lira.ratio);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Replacement {
+    pub old: Currency,
+    pub new: Currency,
+    /// Day the new code took over.
+    pub effective: Date,
+    /// `(old, new)`: `old` units of the old code became `new` units of the
+    /// new one, e.g. `(1.95583, 1)` for `DEM` to `EUR`. `None` when there
+    /// was no single fixed ratio.
+    pub ratio: Option<(Decimal, Decimal)>,
+}
+
+impl Replacement {
+    /// Ratio given as `(old mantissa, old scale, new)`.
+    const fn new(
+        old: Currency,
+        new: Currency,
+        effective: (i32, u8, u8),
+        ratio: Option<(i128, u32, i128)>,
+    ) -> Replacement {
+        let Some(effective) = Date::new(effective.0, effective.1, effective.2) else {
+            panic!("invalid replacement date");
+        };
+        Replacement {
+            old,
+            new,
+            effective,
+            ratio: match ratio {
+                Some((old, scale, new)) => Some((Decimal::new(old, scale), Decimal::new(new, 0))),
+                None => None,
+            },
+        }
+    }
+}
+
+/// What replaced each historic code in its issuing country. Codes split
+/// between several successors (`CSK`, `SUR` outside Russia, `TPE` by `IDR`
+/// and later `USD`) or simply abolished (`ECV`, `XFU`, `XRE`) have no entry;
+/// changeovers without a single fixed ratio (`DDM`, `ZAL`) have no ratio.
+const REPLACEMENTS: &[Replacement] = &[
+    Replacement::new(
+        Currency::AFA,
+        Currency::AFN,
+        (2002, 10, 7),
+        Some((1000, 0, 1)),
+    ),
+    Replacement::new(
+        Currency::ADP,
+        Currency::EUR,
+        (1999, 1, 1),
+        Some((166386, 3, 1)),
+    ),
+    Replacement::new(Currency::ALK, Currency::ALL, (1965, 8, 1), Some((10, 0, 1))),
+    Replacement::new(Currency::AOK, Currency::AON, (1990, 9, 25), Some((1, 0, 1))),
+    Replacement::new(
+        Currency::AON,
+        Currency::AOR,
+        (1995, 7, 1),
+        Some((1000, 0, 1)),
+    ),
+    Replacement::new(
+        Currency::AOR,
+        Currency::AOA,
+        (1999, 12, 1),
+        Some((1_000_000, 0, 1)),
+    ),
+    Replacement::new(
+        Currency::ARY,
+        Currency::ARP,
+        (1983, 6, 1),
+        Some((10_000, 0, 1)),
+    ),
+    Replacement::new(
+        Currency::ARP,
+        Currency::ARA,
+        (1985, 6, 15),
+        Some((1000, 0, 1)),
+    ),
+    Replacement::new(
+        Currency::ARA,
+        Currency::ARS,
+        (1992, 1, 1),
+        Some((10_000, 0, 1)),
+    ),
+    Replacement::new(
+        Currency::ATS,
+        Currency::EUR,
+        (1999, 1, 1),
+        Some((137603, 4, 1)),
+    ),
+    Replacement::new(Currency::AYM, Currency::AZN, (2006, 1, 1), None),
+    Replacement::new(
+        Currency::AZM,
+        Currency::AZN,
+        (2006, 1, 1),
+        Some((5000, 0, 1)),
+    ),
+    Replacement::new(
+        Currency::BAD,
+        Currency::BAM,
+        (1998, 6, 22),
+        Some((100, 0, 1)),
+    ),
+    Replacement::new(Currency::BEC, Currency::BEF, (1990, 3, 5), Some((1, 0, 1))),
+    Replacement::new(Currency::BEL, Currency::BEF, (1990, 3, 5), Some((1, 0, 1))),
+    Replacement::new(
+        Currency::BEF,
+        Currency::EUR,
+        (1999, 1, 1),
+        Some((403399, 4, 1)),
+    ),
+    Replacement::new(Currency::BGJ, Currency::BGK, (1962, 1, 1), Some((10, 0, 1))),
+    Replacement::new(Currency::BGK, Currency::BGL, (1962, 1, 1), None),
+    Replacement::new(
+        Currency::BGL,
+        Currency::BGN,
+        (1999, 7, 5),
+        Some((1000, 0, 1)),
+    ),
+    Replacement::new(
+        Currency::BGN,
+        Currency::EUR,
+        (2026, 1, 1),
+        Some((195583, 5, 1)),
+    ),
+    Replacement::new(
+        Currency::BOP,
+        Currency::BOB,
+        (1987, 1, 1),
+        Some((1_000_000, 0, 1)),
+    ),
+    Replacement::new(
+        Currency::BRB,
+        Currency::BRC,
+        (1986, 2, 28),
+        Some((1000, 0, 1)),
+    ),
+    Replacement::new(
+        Currency::BRC,
+        Currency::BRN,
+        (1989, 1, 16),
+        Some((1000, 0, 1)),
+    ),
+    Replacement::new(Currency::BRN, Currency::BRE, (1990, 3, 16), Some((1, 0, 1))),
+    Replacement::new(
+        Currency::BRE,
+        Currency::BRR,
+        (1993, 8, 1),
+        Some((1000, 0, 1)),
+    ),
+    Replacement::new(
+        Currency::BRR,
+        Currency::BRL,
+        (1994, 7, 1),
+        Some((2750, 0, 1)),
+    ),
+    Replacement::new(Currency::BUK, Currency::MMK, (1989, 6, 18), Some((1, 0, 1))),
+    Replacement::new(
+        Currency::BYB,
+        Currency::BYR,
+        (2000, 1, 1),
+        Some((1000, 0, 1)),
+    ),
+    Replacement::new(
+        Currency::BYR,
+        Currency::BYN,
+        (2016, 7, 1),
+        Some((10_000, 0, 1)),
+    ),
+    Replacement::new(Currency::CHC, Currency::CHW, (2004, 11, 1), None),
+    Replacement::new(
+        Currency::CSD,
+        Currency::RSD,
+        (2006, 10, 25),
+        Some((1, 0, 1)),
+    ),
+    Replacement::new(Currency::CSJ, Currency::CSK, (1953, 6, 1), None),
+    Replacement::new(Currency::CUC, Currency::CUP, (2021, 1, 1), Some((1, 0, 24))),
+    Replacement::new(
+        Currency::CYP,
+        Currency::EUR,
+        (2008, 1, 1),
+        Some((585274, 6, 1)),
+    ),
+    Replacement::new(Currency::DDM, Currency::DEM, (1990, 7, 1), None),
+    Replacement::new(
+        Currency::DEM,
+        Currency::EUR,
+        (1999, 1, 1),
+        Some((195583, 5, 1)),
+    ),
+    Replacement::new(
+        Currency::ECS,
+        Currency::USD,
+        (2000, 3, 13),
+        Some((25_000, 0, 1)),
+    ),
+    Replacement::new(
+        Currency::EEK,
+        Currency::EUR,
+        (2011, 1, 1),
+        Some((156466, 4, 1)),
+    ),
+    Replacement::new(Currency::ESA, Currency::ESP, (1981, 1, 1), None),
+    Replacement::new(Currency::ESB, Currency::ESP, (1994, 12, 1), None),
+    Replacement::new(
+        Currency::ESP,
+        Currency::EUR,
+        (1999, 1, 1),
+        Some((166386, 3, 1)),
+    ),
+    Replacement::new(
+        Currency::FIM,
+        Currency::EUR,
+        (1999, 1, 1),
+        Some((594573, 5, 1)),
+    ),
+    Replacement::new(
+        Currency::FRF,
+        Currency::EUR,
+        (1999, 1, 1),
+        Some((655957, 5, 1)),
+    ),
+    Replacement::new(
+        Currency::GEK,
+        Currency::GEL,
+        (1995, 9, 25),
+        Some((1_000_000, 0, 1)),
+    ),
+    Replacement::new(
+        Currency::GHC,
+        Currency::GHS,
+        (2007, 7, 1),
+        Some((10_000, 0, 1)),
+    ),
+    Replacement::new(Currency::GHP, Currency::GHS, (2007, 7, 1), Some((1, 0, 1))),
+    Replacement::new(Currency::GNE, Currency::GNF, (1986, 1, 6), None),
+    Replacement::new(Currency::GNS, Currency::GNF, (1986, 1, 6), Some((1, 0, 1))),
+    Replacement::new(Currency::GQE, Currency::XAF, (1985, 1, 2), None),
+    Replacement::new(
+        Currency::GRD,
+        Currency::EUR,
+        (2001, 1, 1),
+        Some((340750, 3, 1)),
+    ),
+    Replacement::new(Currency::GWE, Currency::GWP, (1976, 2, 28), Some((1, 0, 1))),
+    Replacement::new(Currency::GWP, Currency::XOF, (1997, 5, 2), Some((65, 0, 1))),
+    Replacement::new(
+        Currency::HRD,
+        Currency::HRK,
+        (1994, 5, 30),
+        Some((1000, 0, 1)),
+    ),
+    Replacement::new(
+        Currency::HRK,
+        Currency::EUR,
+        (2023, 1, 1),
+        Some((753450, 5, 1)),
+    ),
+    Replacement::new(
+        Currency::IEP,
+        Currency::EUR,
+        (1999, 1, 1),
+        Some((787564, 6, 1)),
+    ),
+    Replacement::new(
+        Currency::ILP,
+        Currency::ILR,
+        (1980, 2, 24),
+        Some((10, 0, 1)),
+    ),
+    Replacement::new(
+        Currency::ILR,
+        Currency::ILS,
+        (1985, 9, 4),
+        Some((1000, 0, 1)),
+    ),
+    Replacement::new(
+        Currency::ISJ,
+        Currency::ISK,
+        (1981, 1, 1),
+        Some((100, 0, 1)),
+    ),
+    Replacement::new(
+        Currency::ITL,
+        Currency::EUR,
+        (1999, 1, 1),
+        Some((193627, 2, 1)),
+    ),
+    Replacement::new(
+        Currency::LAJ,
+        Currency::LAK,
+        (1979, 12, 16),
+        Some((100, 0, 1)),
+    ),
+    Replacement::new(Currency::LSM, Currency::LSL, (1985, 5, 1), Some((1, 0, 1))),
+    Replacement::new(
+        Currency::LTT,
+        Currency::LTL,
+        (1993, 6, 25),
+        Some((100, 0, 1)),
+    ),
+    Replacement::new(
+        Currency::LTL,
+        Currency::EUR,
+        (2015, 1, 1),
+        Some((345280, 5, 1)),
+    ),
+    Replacement::new(Currency::LUC, Currency::LUF, (1990, 3, 5), Some((1, 0, 1))),
+    Replacement::new(Currency::LUL, Currency::LUF, (1990, 3, 5), Some((1, 0, 1))),
+    Replacement::new(
+        Currency::LUF,
+        Currency::EUR,
+        (1999, 1, 1),
+        Some((403399, 4, 1)),
+    ),
+    Replacement::new(
+        Currency::LVR,
+        Currency::LVL,
+        (1993, 3, 5),
+        Some((200, 0, 1)),
+    ),
+    Replacement::new(
+        Currency::LVL,
+        Currency::EUR,
+        (2014, 1, 1),
+        Some((702804, 6, 1)),
+    ),
+    Replacement::new(Currency::MGF, Currency::MGA, (2005, 1, 1), Some((5, 0, 1))),
+    Replacement::new(Currency::MLF, Currency::XOF, (1984, 6, 1), Some((2, 0, 1))),
+    Replacement::new(Currency::MRO, Currency::MRU, (2018, 1, 1), Some((10, 0, 1))),
+    Replacement::new(Currency::MTP, Currency::MTL, (1983, 6, 1), Some((1, 0, 1))),
+    Replacement::new(
+        Currency::MTL,
+        Currency::EUR,
+        (2008, 1, 1),
+        Some((429300, 6, 1)),
+    ),
+    Replacement::new(Currency::MVQ, Currency::MVR, (1981, 10, 1), Some((1, 0, 1))),
+    Replacement::new(
+        Currency::MXP,
+        Currency::MXN,
+        (1993, 1, 1),
+        Some((1000, 0, 1)),
+    ),
+    Replacement::new(Currency::MZE, Currency::MZM, (1980, 6, 16), Some((1, 0, 1))),
+    Replacement::new(
+        Currency::MZM,
+        Currency::MZN,
+        (2006, 7, 1),
+        Some((1000, 0, 1)),
+    ),
+    Replacement::new(
+        Currency::NIC,
+        Currency::NIO,
+        (1991, 4, 30),
+        Some((5_000_000, 0, 1)),
+    ),
+    Replacement::new(
+        Currency::NLG,
+        Currency::EUR,
+        (1999, 1, 1),
+        Some((220371, 5, 1)),
+    ),
+    Replacement::new(Currency::ANG, Currency::XCG, (2025, 3, 31), Some((1, 0, 1))),
+    Replacement::new(
+        Currency::PEH,
+        Currency::PEI,
+        (1985, 2, 1),
+        Some((1000, 0, 1)),
+    ),
+    Replacement::new(
+        Currency::PES,
+        Currency::PEI,
+        (1985, 2, 1),
+        Some((1000, 0, 1)),
+    ),
+    Replacement::new(
+        Currency::PEI,
+        Currency::PEN,
+        (1991, 7, 1),
+        Some((1_000_000, 0, 1)),
+    ),
+    Replacement::new(
+        Currency::PLZ,
+        Currency::PLN,
+        (1995, 1, 1),
+        Some((10_000, 0, 1)),
+    ),
+    Replacement::new(
+        Currency::PTE,
+        Currency::EUR,
+        (1999, 1, 1),
+        Some((200482, 3, 1)),
+    ),
+    Replacement::new(Currency::RHD, Currency::ZWC, (1980, 4, 18), Some((1, 0, 1))),
+    Replacement::new(Currency::ROK, Currency::ROL, (1952, 1, 28), None),
+    Replacement::new(
+        Currency::ROL,
+        Currency::RON,
+        (2005, 7, 1),
+        Some((10_000, 0, 1)),
+    ),
+    Replacement::new(
+        Currency::RUR,
+        Currency::RUB,
+        (1998, 1, 1),
+        Some((1000, 0, 1)),
+    ),
+    Replacement::new(Currency::SDP, Currency::SDD, (1992, 6, 8), Some((10, 0, 1))),
+    Replacement::new(
+        Currency::SDD,
+        Currency::SDG,
+        (2007, 1, 10),
+        Some((100, 0, 1)),
+    ),
+    Replacement::new(
+        Currency::SIT,
+        Currency::EUR,
+        (2007, 1, 1),
+        Some((239640, 3, 1)),
+    ),
+    Replacement::new(
+        Currency::SKK,
+        Currency::EUR,
+        (2009, 1, 1),
+        Some((301260, 4, 1)),
+    ),
+    Replacement::new(
+        Currency::SLL,
+        Currency::SLE,
+        (2022, 7, 1),
+        Some((1000, 0, 1)),
+    ),
+    Replacement::new(
+        Currency::SRG,
+        Currency::SRD,
+        (2004, 1, 1),
+        Some((1000, 0, 1)),
+    ),
+    Replacement::new(
+        Currency::STD,
+        Currency::STN,
+        (2018, 1, 1),
+        Some((1000, 0, 1)),
+    ),
+    Replacement::new(Currency::SUR, Currency::RUR, (1992, 1, 1), Some((1, 0, 1))),
+    Replacement::new(
+        Currency::TJR,
+        Currency::TJS,
+        (2000, 10, 30),
+        Some((1000, 0, 1)),
+    ),
+    Replacement::new(
+        Currency::TMM,
+        Currency::TMT,
+        (2009, 1, 1),
+        Some((5000, 0, 1)),
+    ),
+    Replacement::new(
+        Currency::TRL,
+        Currency::TRY,
+        (2005, 1, 1),
+        Some((1_000_000, 0, 1)),
+    ),
+    Replacement::new(
+        Currency::UAK,
+        Currency::UAH,
+        (1996, 9, 2),
+        Some((100_000, 0, 1)),
+    ),
+    Replacement::new(Currency::UGW, Currency::UGS, (1987, 5, 18), None),
+    Replacement::new(
+        Currency::UGS,
+        Currency::UGX,
+        (1987, 5, 18),
+        Some((100, 0, 1)),
+    ),
+    Replacement::new(Currency::USS, Currency::USD, (2014, 3, 1), Some((1, 0, 1))),
+    Replacement::new(
+        Currency::UYN,
+        Currency::UYP,
+        (1975, 7, 1),
+        Some((1000, 0, 1)),
+    ),
+    Replacement::new(
+        Currency::UYP,
+        Currency::UYU,
+        (1993, 3, 1),
+        Some((1000, 0, 1)),
+    ),
+    Replacement::new(
+        Currency::VEB,
+        Currency::VEF,
+        (2008, 1, 1),
+        Some((1000, 0, 1)),
+    ),
+    Replacement::new(
+        Currency::VEF,
+        Currency::VES,
+        (2018, 8, 20),
+        Some((100_000, 0, 1)),
+    ),
+    Replacement::new(
+        Currency::VNC,
+        Currency::VND,
+        (1985, 9, 14),
+        Some((10, 0, 1)),
+    ),
+    Replacement::new(Currency::XEU, Currency::EUR, (1999, 1, 1), Some((1, 0, 1))),
+    Replacement::new(Currency::XFO, Currency::XDR, (2003, 4, 1), None),
+    Replacement::new(
+        Currency::YDD,
+        Currency::YER,
+        (1990, 5, 22),
+        Some((1, 0, 26)),
+    ),
+    Replacement::new(
+        Currency::YUD,
+        Currency::YUN,
+        (1990, 1, 1),
+        Some((10_000, 0, 1)),
+    ),
+    Replacement::new(Currency::YUN, Currency::YUM, (1994, 1, 24), None),
+    Replacement::new(Currency::YUM, Currency::CSD, (2003, 7, 1), Some((1, 0, 1))),
+    Replacement::new(Currency::ZAL, Currency::ZAR, (1995, 3, 13), None),
+    Replacement::new(
+        Currency::ZMK,
+        Currency::ZMW,
+        (2013, 1, 1),
+        Some((1000, 0, 1)),
+    ),
+    Replacement::new(
+        Currency::ZRZ,
+        Currency::ZRN,
+        (1993, 10, 22),
+        Some((3_000_000, 0, 1)),
+    ),
+    Replacement::new(
+        Currency::ZRN,
+        Currency::CDF,
+        (1998, 6, 30),
+        Some((100_000, 0, 1)),
+    ),
+    Replacement::new(Currency::ZWC, Currency::ZWD, (1989, 12, 1), Some((1, 0, 1))),
+    Replacement::new(
+        Currency::ZWD,
+        Currency::ZWN,
+        (2006, 8, 1),
+        Some((1000, 0, 1)),
+    ),
+    Replacement::new(
+        Currency::ZWN,
+        Currency::ZWR,
+        (2008, 8, 1),
+        Some((10_000_000_000, 0, 1)),
+    ),
+    Replacement::new(
+        Currency::ZWR,
+        Currency::ZWL,
+        (2009, 2, 2),
+        Some((1_000_000_000_000, 0, 1)),
+    ),
+    Replacement::new(
+        Currency::ZWL,
+        Currency::ZWG,
+        (2024, 4, 5),
+        Some((24987242, 4, 1)),
+    ),
+];
+
+/// Codes withdrawn in favour of one already circulating beside them, e.g.
+/// Belgium's convertible and financial francs for `BEF`.
+pub(crate) const MERGED: &[Currency] = &[
+    Currency::BEC,
+    Currency::BEL,
+    Currency::CUC,
+    Currency::DDM,
+    Currency::ESA,
+    Currency::ESB,
+    Currency::LUC,
+    Currency::LUL,
+    Currency::USS,
+    Currency::YDD,
+    Currency::ZAL,
+];
+
+/// Codes given up for a currency already in use elsewhere, e.g. `ECS` for
+/// `USD` or `MLF` for `XOF`.
+const ADOPTED: &[Currency] = &[
+    Currency::ECS,
+    Currency::GQE,
+    Currency::GWP,
+    Currency::MLF,
+    Currency::XFO,
+];
+
+impl Currency {
+    /// The code that replaced this one, e.g. `XCG` for `ANG`.
+    /// #Sample
+    /// ```
+    /// use rust_iso4217::Currency;
+    ///
+    /// assert_eq!(Some(Currency::ZWN), Currency::ZWD.successor());
+    /// assert_eq!(None, Currency::USD.successor());
+    /// ```
+    pub fn successor(self) -> Option<Currency> {
+        Some(self.replacement()?.new)
+    }
+
+    /// When and at what ratio this code was replaced.
+    pub fn replacement(self) -> Option<&'static Replacement> {
+        REPLACEMENTS
+            .iter()
+            .find(|replacement| replacement.old == self)
+    }
+
+    /// Codes this one directly replaced, e.g. every legacy code for `EUR`.
+    pub fn predecessors(self) -> CurrencySet {
+        REPLACEMENTS
+            .iter()
+            .filter(|replacement| replacement.new == self)
+            .map(|replacement| replacement.old)
+            .collect()
+    }
+
+    /// Day this code was introduced, when a replacement records it: the
+    /// earliest one that brought it into use, e.g. 1999-01-01 for `EUR`.
+    /// #Sample
+    /// ```
+    /// use rust_iso4217::{Currency, Date};
+    ///
+    /// assert_eq!(Date::new(2025, 3, 31), Currency::XCG.introduced());
+    /// // Ecuador's dollarisation did not introduce the dollar.
+    /// assert_eq!(None, Currency::USD.introduced());
+    /// ```
+    pub fn introduced(self) -> Option<Date> {
+        REPLACEMENTS
+            .iter()
+            .filter(|replacement| {
+                replacement.new == self
+                    && !MERGED.contains(&replacement.old)
+                    && !ADOPTED.contains(&replacement.old)
+            })
+            .map(|replacement| replacement.effective)
+            .min()
+    }
+
+    /// Follows [`Currency::successor`] to the code in use today; `None` when
+    /// the chain ends at a historic code.
+    /// #Sample
+    /// ```
+    /// use rust_iso4217::Currency;
+    ///
+    /// assert_eq!(Some(Currency::ZWG), Currency::ZWD.resolve_current());
+    /// assert_eq!(Some(Currency::EUR), Currency::DDM.resolve_current());
+    /// assert_eq!(None, Currency::CSK.resolve_current());
+    /// ```
+    pub fn resolve_current(self) -> Option<Currency> {
+        let mut current = self;
+        while current.is_historic() {
+            current = current.successor()?;
+        }
+        Some(current)
+    }
+}
+
+impl CurrencyCode {
+    /// See [`Currency::successor`].
+    pub fn successor(&self) -> Option<CurrencyCode> {
+        Some(*self.currency()?.successor()?.currency_code())
+    }
+
+    /// See [`Currency::replacement`].
+    pub fn replacement(&self) -> Option<&'static Replacement> {
+        self.currency()?.replacement()
+    }
+
+    /// See [`Currency::predecessors`].
+    pub fn predecessors(&self) -> CurrencySet {
+        self.currency()
+            .map_or(CurrencySet::new(), Currency::predecessors)
+    }
+
+    /// See [`Currency::introduced`].
+    pub fn introduced(&self) -> Option<Date> {
+        self.currency()?.introduced()
+    }
+}
+
+/// Maps a code, e.g. from a legacy record, to the code in use today by
+/// following its successors. Active codes map to themselves.
+/// #Sample
+/// ```
+/// assert_eq!(Some(rust_iso4217::TRY), rust_iso4217::resolve_current(rust_iso4217::TRL));
+/// assert_eq!(Some(rust_iso4217::USD), rust_iso4217::resolve_current(rust_iso4217::USD));
+/// ```
+pub fn resolve_current(code: CurrencyCode) -> Option<CurrencyCode> {
+    Some(*code.currency()?.resolve_current()?.currency_code())
+}
+
+/// Decimals tried for an exact decimal remainder.
+const REMAINDER_SCALE: u32 = 18;
+
+/// An amount restated in the code in use today, and what rounding dropped.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Restated {
+    pub value: Money,
+    /// `(numerator, denominator)`: the remainder in units of the current
+    /// code is exactly `numerator / denominator`, the denominator being the
+    /// composed old side of the ratios.
+    pub remainder: (Decimal, Decimal),
+}
+
+impl Restated {
+    /// The remainder as a decimal, when it has one within 18 decimals that
+    /// fits.
+    ///
+    /// Redenominations by powers of ten always have one; ratios such as
+    /// `DEM` to `EUR` generally do not.
+    pub fn exact_remainder(&self) -> Option<Decimal> {
+        exact_div(self.remainder.0, self.remainder.1)
+    }
+}
+
+/// Restates `amount` of `code` in the code in use today, applying the
+/// ratio of every replacement along the way, e.g. `TRL` to `TRY` at
+/// 1,000,000 to 1.
+///
+/// The composed ratio is applied exactly and the result rounded once to the
+/// current code's minor unit. The remainder is kept as an exact fraction;
+/// see [`Restated::exact_remainder`]. Active codes are only rounded.
+/// #Sample
+/// ```
+/// use rust_iso4217::{Decimal, RoundingMode};
+///
+/// let balance = Decimal::new(12_345_678_901, 0); // TRL
+/// let restated = rust_iso4217::restate(balance, rust_iso4217::TRL, RoundingMode::HalfEven).unwrap();
+/// assert_eq!("12345.68 TRY", restated.value.to_string());
+/// assert_eq!(Some(Decimal::new(-1_099, 6)), restated.exact_remainder());
+/// let marks = rust_iso4217::restate(Decimal::new(1, 0), rust_iso4217::DEM, RoundingMode::HalfEven).unwrap();
+/// assert_eq!(None, marks.exact_remainder());
+/// ```
+pub fn restate(
+    amount: Decimal,
+    code: CurrencyCode,
+    mode: RoundingMode,
+) -> Result<Restated, MoneyError> {
+    let mut currency = Currency::try_from(code)?;
+    let (mut old, mut new) = (Decimal::new(1, 0), Decimal::new(1, 0));
+    while currency.is_historic() {
+        let replacement = currency
+            .replacement()
+            .ok_or(MoneyError::NoSuccessor(currency.code()))?;
+        let (old_units, new_units) = replacement.ratio.ok_or(MoneyError::MissingRate(
+            currency.code(),
+            replacement.new.code(),
+        ))?;
+        old = old.checked_mul(old_units).ok_or(MoneyError::Overflow)?;
+        new = new.checked_mul(new_units).ok_or(MoneyError::Overflow)?;
+        currency = replacement.new;
+    }
+    let target = *currency.currency_code();
+    let unit = target.minor_unit().ok_or(MoneyError::NoMinorUnit)?;
+    // amount × new / old = value + rest / old
+    let numerator = amount.checked_mul(new).ok_or(MoneyError::Overflow)?;
+    let value = numerator
+        .checked_div(old, unit as u32, mode)
+        .ok_or(MoneyError::Overflow)?;
+    let rest = value
+        .checked_mul(old)
+        .and_then(|product| numerator.checked_sub(product))
+        .ok_or(MoneyError::Overflow)?;
+    let minor = i64::try_from(value.mantissa()).map_err(|_| MoneyError::Overflow)?;
+    Ok(Restated {
+        value: Money::from_minor(minor, target)?,
+        remainder: (rest, old),
+    })
+}
+
+/// `lhs / rhs` when it has a decimal form that fits.
+fn exact_div(lhs: Decimal, rhs: Decimal) -> Option<Decimal> {
+    (lhs.scale()..=REMAINDER_SCALE.max(lhs.scale())).find_map(|scale| {
+        let quotient = lhs.checked_div(rhs, scale, RoundingMode::TowardZero)?;
+        let product = quotient.checked_mul(rhs)?;
+        lhs.checked_sub(product)?.is_zero().then_some(quotient)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Currency, CurrencySet, Date, WITHDRAWALS};
+
+    #[test]
+    fn test_withdrawals() {
+        assert!(WITHDRAWALS
+            .windows(2)
+            .all(|pair| (pair[0].currency, pair[0].from) <= (pair[1].currency, pair[1].from)));
+        for currency in Currency::iter().filter(|c| c.is_historic()) {
+            assert!(!currency.withdrawals().is_empty(), "{}", currency);
+        }
+        assert!(Currency::USD.withdrawals().is_empty());
+        assert_eq!(
+            vec!["SERBIA AND MONTENEGRO"],
+            Currency::EUR
+                .withdrawals()
+                .iter()
+                .map(|w| w.entity)
+                .collect::<Vec<_>>()
+        );
+        let ddm = Currency::DDM.withdrawals()[0];
+        assert_eq!(Date::new(1990, 7, 1), Some(ddm.from));
+        assert_eq!(Date::new(1990, 9, 30), Some(ddm.until));
+        let ary = Currency::ARY.withdrawals()[0];
+        assert_eq!(Date::new(1989, 1, 1), Some(ary.from));
+        assert_eq!(Date::new(1990, 12, 31), Some(ary.until));
+        assert_eq!(11, Currency::RUR.withdrawals().len());
+    }
+
+    #[test]
+    fn test_is_valid_on() {
+        let day = |s: &str| s.parse::<Date>().unwrap();
+        assert!(crate::FRF.is_valid_on(day("2002-03-31")));
+        assert!(!crate::FRF.is_valid_on(day("2002-04-01")));
+        // Withdrawn in 2006 and again in 2008.
+        assert!(crate::ZWD.is_valid_on(day("2007-05-01")));
+        assert!(!crate::ZWD.is_valid_on(day("2008-09-01")));
+        assert!(crate::RUR.is_valid_on(day("2003-12-31")));
+        assert!(!crate::RUR.is_valid_on(day("2004-02-01")));
+        assert!(crate::BGN.is_valid_on(day("2025-12-31")));
+        assert!(!crate::BGN.is_valid_on(day("2026-02-01")));
+        assert!(crate::PEN.is_valid_on(day("2030-01-01")));
+        assert!(!crate::PEN.is_valid_on(day("1991-06-30")));
+        assert!(!crate::EUR.is_valid_on(day("1990-01-01")));
+        assert!(crate::EUR.is_valid_on(day("1999-01-01")));
+        assert!(!crate::XCG.is_valid_on(day("1990-01-01")));
+        assert!(!crate::HRK.is_valid_on(day("1994-05-29")));
+        // Already in circulation when they absorbed another code.
+        assert!(crate::CUP.is_valid_on(day("2015-01-01")));
+        assert!(crate::XOF.is_valid_on(day("1980-01-01")));
+        assert!(crate::DEM.is_valid_on(day("1980-01-01")));
+    }
+
+    #[test]
+    fn test_successors() {
+        let mut chain = vec![Currency::ZWD];
+        while let Some(next) = chain.last().unwrap().successor() {
+            chain.push(next);
+        }
+        assert_eq!(
+            vec!["ZWD", "ZWN", "ZWR", "ZWL", "ZWG"],
+            chain.iter().map(|c| c.code()).collect::<Vec<_>>()
+        );
+        for currency in Currency::iter() {
+            // Chains end, and only historic codes are replaced.
+            assert!(currency.successor().is_none() || currency.is_historic());
+            let _ = currency.resolve_current();
+        }
+        assert_eq!(
+            CurrencySet::new().with(Currency::AYM).with(Currency::AZM),
+            Currency::AZN.predecessors()
+        );
+        assert!(Currency::EUR.predecessors().contains(Currency::DEM));
+        assert_eq!(Some(crate::XCG), crate::ANG.successor());
+        assert_eq!(Some(crate::SLE), crate::resolve_current(crate::SLL));
+        assert_eq!(Some(crate::RUB), crate::resolve_current(crate::SUR));
+        assert_eq!(None, crate::resolve_current(crate::XRE));
+        // Every other historic code has a successor.
+        let without: CurrencySet = Currency::iter()
+            .filter(|c| c.is_historic() && c.successor().is_none())
+            .collect();
+        assert_eq!(
+            CurrencySet::new()
+                .with(Currency::CSK)
+                .with(Currency::ECV)
+                .with(Currency::TPE)
+                .with(Currency::XFU)
+                .with(Currency::XRE),
+            without
+        );
+        assert_eq!(None, crate::resolve_current(crate::TPE));
+    }
+
+    #[test]
+    fn test_restate() {
+        use crate::{restate, Decimal, MoneyError, RoundingMode};
+
+        let restated = |amount: &str, code| {
+            restate(amount.parse().unwrap(), code, RoundingMode::HalfUp).map(|r| {
+                let remainder = match r.exact_remainder() {
+                    Some(remainder) => remainder.to_string(),
+                    None => format!("{}/{}", r.remainder.0, r.remainder.1),
+                };
+                (r.value.to_string(), remainder)
+            })
+        };
+        let pair = |value: &str, remainder: &str| Ok((value.to_string(), remainder.to_string()));
+        assert_eq!(pair("1.23 RON", "0.0045"), restated("12345", crate::ROL));
+        assert_eq!(pair("-0.01 VES", "0.00"), restated("-1000", crate::VEF));
+        assert_eq!(
+            pair("1234.57 SLE", "-0.003"),
+            restated("1234567", crate::SLL)
+        );
+        // 1000 - 511.29 × 1.95583, over 1.95583: no decimal form.
+        assert_eq!(
+            pair("511.29 EUR", "0.0036793/1.95583"),
+            restated("1000", crate::DEM)
+        );
+        // ZWD to ZWG in four steps.
+        let zwd = restate(
+            "100000000000000000000000000000".parse().unwrap(),
+            crate::ZWD,
+            RoundingMode::HalfUp,
+        )
+        .unwrap();
+        assert_eq!("4.00 ZWG", zwd.value.to_string());
+        // 51032 / 24987242, which has no decimal form.
+        assert_eq!(None, zwd.exact_remainder());
+        let (numerator, denominator) = zwd.remainder;
+        assert_eq!(
+            Some(Decimal::new(204_232, 8)),
+            numerator.checked_div(denominator, 8, RoundingMode::HalfUp)
+        );
+        let zwc = restate(Decimal::new(1, 0), crate::ZWC, RoundingMode::HalfUp).unwrap();
+        assert_eq!("0.00 ZWG", zwc.value.to_string());
+        assert!(!zwc.remainder.0.is_zero());
+        assert_eq!(None, zwc.exact_remainder());
+        assert_eq!(pair("240.00 CUP", "0.00"), restated("10", crate::CUC));
+        assert_eq!(pair("3.14 USD", "-0.001"), restated("3.139", crate::USD));
+        assert_eq!(
+            Err(MoneyError::MissingRate("DDM", "DEM")),
+            restate(Decimal::new(1, 0), crate::DDM, RoundingMode::HalfUp)
+        );
+        assert_eq!(
+            Err(MoneyError::NoSuccessor("CSK")),
+            restate(Decimal::new(1, 0), crate::CSK, RoundingMode::HalfUp)
+        );
+    }
+}
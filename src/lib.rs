@@ -1,3 +1,13 @@
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(all(target_arch = "wasm32", not(feature = "std")))]
+compile_error!("the wasm bindings require the `std` feature");
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
 use phf::{phf_map, Map};
 #[cfg(target_arch = "wasm32")]
 use wasm_bindgen::prelude::*;
@@ -5,6 +15,60 @@ use wasm_bindgen::prelude::*;
 #[cfg(target_arch = "wasm32")]
 use js_sys::Array;
 
+mod bag;
+mod code_type;
+mod collections;
+mod country;
+#[cfg(feature = "alloc")]
+mod conversion;
+mod currency;
+mod date;
+mod decimal;
+mod error;
+mod euro;
+mod history;
+#[cfg(feature = "alloc")]
+mod loader;
+mod lookup;
+mod macros;
+mod minor_unit;
+mod money;
+mod parse;
+mod provider;
+mod rate;
+#[cfg(feature = "serde")]
+pub mod serde;
+#[cfg(feature = "alloc")]
+mod series;
+#[cfg(feature = "alloc")]
+mod triangulate;
+pub use bag::MoneyBag;
+pub use code_type::CodeType;
+pub use collections::{CurrencyMap, CurrencySet, CurrencySetIter};
+pub use country::from_country_on;
+#[cfg(feature = "alloc")]
+pub use conversion::{convert_with, Conversion};
+pub use date::Date;
+pub use decimal::{Decimal, Rounded, RoundingMode};
+pub use error::{
+    CountryError, DateError, LoadError, MinorUnitError, MoneyError, ParseCurrencyError, RateError,
+};
+pub use euro::euro_convert;
+pub use history::{resolve_current, restate, Replacement, Restated, Withdrawal};
+#[cfg(feature = "alloc")]
+pub use loader::{LoadIssue, RateFormat};
+#[doc(hidden)]
+pub use macros::__private;
+pub use money::Money;
+#[cfg(feature = "alloc")]
+pub use provider::OfflineRates;
+pub use provider::RateProvider;
+pub use rate::{convert, ExchangeRate};
+#[cfg(feature = "alloc")]
+pub use series::RateSeries;
+#[cfg(feature = "alloc")]
+pub use triangulate::{Triangulated, Triangulator};
+
 #[cfg(test)]
 mod tests {
     #[test]
@@ -25,6 +89,28 @@ mod tests {
         print!("test_from_code result {:?}", l)
     }
 
+    #[test]
+    fn test_from_country() {
+        assert_eq!(crate::from_country_slice("hrv").len(), 1);
+        assert_eq!(crate::from_country_slice("hrv")[0].code, "EUR");
+        assert!(crate::from_country_slice("ATA").is_empty());
+        assert!(crate::from_country_slice("").is_empty());
+        #[cfg(feature = "alloc")]
+        assert_eq!(crate::from_country("hrv"), vec![crate::EUR]);
+        assert_eq!(
+            crate::try_from_country("SGS"),
+            Err(crate::CountryError::NoCurrency)
+        );
+        assert_eq!(
+            crate::try_from_country("XYZ"),
+            Err(crate::CountryError::UnknownCountry)
+        );
+        assert_eq!(
+            crate::try_from_country("U$A"),
+            Err(crate::CountryError::Malformed)
+        );
+    }
+
     #[test]
     fn test_all() {
         println!("{:?}", crate::ALL);
@@ -45,7 +131,7 @@ pub struct CurrencyCode {
     //Minor unit
     unit: i32,
     //type
-    code_type: &'static str,
+    code_type: CodeType,
     //countries
     countries: &'static [&'static str],
 }
@@ -62,7 +148,7 @@ pub struct CurrencyCode {
     //Minor unit
     pub unit: i32,
     //type
-    pub code_type: &'static str,
+    pub code_type: CodeType,
     pub countries: &'static [&'static str],
 }
 
@@ -88,8 +174,8 @@ impl CurrencyCode {
         self.unit
     }
     #[wasm_bindgen(getter)]
-    pub fn code_type(&self) -> String {
-        self.code_type.into()
+    pub fn code_type(&self) -> CodeType {
+        self.code_type
     }
     #[wasm_bindgen(getter)]
     pub fn countries(&self) -> Array {
@@ -110,8 +196,7 @@ impl CurrencyCode {
 /// ```
 #[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
 pub fn from_code(alpha3: &str) -> Option<CurrencyCode> {
-    let up = alpha3.to_uppercase();
-    ALL_MAP.get(&up).cloned()
+    lookup::alpha3(alpha3.as_bytes()).map(Currency::into)
 }
 /// Returns the CurrencyCode with the given numeric , if exists.
 // #Sample
@@ -121,8 +206,8 @@ pub fn from_code(alpha3: &str) -> Option<CurrencyCode> {
 /// ```
 #[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
 pub fn from_numeric(numeric: i32) -> Option<CurrencyCode> {
-    let k = format!("{:03}", numeric);
-    NUMERIC_MAP.get(&k).cloned()
+    let numeric = u16::try_from(numeric).ok()?;
+    lookup::numeric(numeric).map(Currency::into)
 }
 
 /// Returns the CurrencyCode with the given numeric 3 length str, if exists.
@@ -133,44 +218,84 @@ pub fn from_numeric(numeric: i32) -> Option<CurrencyCode> {
 /// ```
 #[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
 pub fn from_numeric_str(numeric: &str) -> Option<CurrencyCode> {
-    NUMERIC_MAP.get(numeric).cloned()
+    let numeric = lookup::numeric_digits(numeric.as_bytes())?;
+    lookup::numeric(numeric).map(Currency::into)
 }
 
-/// Returns the CurrencyCode with the given numeric 3 length str, if exists.
+/// Returns the CurrencyCode list used by the given ISO 3166-1 alpha-3 country, or an empty list.
 // #Sample
 /// ```
 /// let currencies = rust_iso4217::from_country("CHN");
 /// assert_eq!(currencies.len() > 0,true)
 /// ```
-#[cfg(not(target_arch = "wasm32"))]
+#[cfg(all(not(target_arch = "wasm32"), feature = "alloc"))]
 pub fn from_country(country: &str) -> Vec<CurrencyCode> {
-    let mut vector: Vec<CurrencyCode> = Vec::new();
-    let currencies = COUNTRY_MAP.get(country);
-    match currencies {
-        Some(cs) => {
-            for i in 0..cs.len() {
-                vector.push(cs[i].clone())
-            }
-        }
-        None => todo!(),
-    }
-    vector
+    try_from_country(country)
+        .map(|cs| cs.iter().map(|c| **c).collect())
+        .unwrap_or_default()
+}
+
+/// Returns the CurrencyCode table slice for the given ISO 3166-1 alpha-3 country, or an empty
+/// slice. Allocation free, so available without the `alloc` feature.
+// #Sample
+/// ```
+/// let currencies = rust_iso4217::from_country_slice("CHN");
+/// assert_eq!("CNY", currencies[0].code);
+/// assert!(rust_iso4217::from_country_slice("ZZZ").is_empty());
+/// ```
+#[cfg(not(target_arch = "wasm32"))]
+pub fn from_country_slice(country: &str) -> &'static [&'static CurrencyCode] {
+    try_from_country(country).unwrap_or(&[])
 }
 
 #[cfg(target_arch = "wasm32")]
 #[wasm_bindgen]
 pub fn from_country(country: &str) -> Array {
-    let mut vector: Vec<CurrencyCode> = Vec::new();
-    let currencies = COUNTRY_MAP.get(country);
-    match currencies {
-        Some(cs) => {
-            for i in 0..cs.len() {
-                vector.push(cs[i].clone())
-            }
-        }
-        None => todo!(),
+    match lookup_country(country) {
+        Ok(cs) => cs.iter().map(|c| JsValue::from(**c)).collect(),
+        Err(_) => Array::new(),
+    }
+}
+
+/// Returns the CurrencyCode list used by the given ISO 3166-1 alpha-3 country, case-insensitively.
+// #Sample
+/// ```
+/// use rust_iso4217::CountryError;
+///
+/// let currencies = rust_iso4217::try_from_country("che").unwrap();
+/// assert_eq!("CHF", currencies[0].code);
+/// assert_eq!(Err(CountryError::NoCurrency), rust_iso4217::try_from_country("ATA"));
+/// assert_eq!(Err(CountryError::UnknownCountry), rust_iso4217::try_from_country("ZZZ"));
+/// assert_eq!(Err(CountryError::Malformed), rust_iso4217::try_from_country("CH"));
+/// ```
+#[cfg(not(target_arch = "wasm32"))]
+pub fn try_from_country(country: &str) -> Result<&'static [&'static CurrencyCode], CountryError> {
+    lookup_country(country)
+}
+
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen]
+pub fn try_from_country(country: &str) -> Result<Array, JsError> {
+    let currencies = lookup_country(country)?;
+    Ok(currencies.iter().map(|c| JsValue::from(**c)).collect())
+}
+
+fn lookup_country(country: &str) -> Result<&'static [&'static CurrencyCode], CountryError> {
+    let bytes = country.as_bytes();
+    if bytes.len() != 3 || !bytes.iter().all(u8::is_ascii_alphabetic) {
+        return Err(CountryError::Malformed);
+    }
+    let mut up = [0u8; 3];
+    for (u, b) in up.iter_mut().zip(bytes) {
+        *u = b.to_ascii_uppercase();
+    }
+    // ASCII letters only, so this cannot fail.
+    let key = core::str::from_utf8(&up).map_err(|_| CountryError::Malformed)?;
+    match COUNTRY_MAP.get(key) {
+        Some(cs) => Ok(cs),
+        None if NO_CURRENCY_COUNTRY.contains(&key) => Err(CountryError::NoCurrency),
+        None => Err(CountryError::UnknownCountry),
     }
-    vector.into_iter().map(JsValue::from).collect()
 }
 
 #[cfg(target_arch = "wasm32")]
@@ -210,7 +335,7 @@ pub const AFN: CurrencyCode = CurrencyCode {
     code: "AFN",
     numeric: 971,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["AFG"],
 
 };
@@ -221,7 +346,7 @@ pub const EUR: CurrencyCode = CurrencyCode {
     code: "EUR",
     numeric: 978,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["ALA","AND","AUT","BEL","BGR","HRV","CYP","EST","FIN","FRA","GUF","ATF","DEU","GRC","GLP","VAT","IRL","ITA","LVA","LTU","LUX","MLT","MTQ","MYT","MCO","MNE","NLD","PRT","REU","BLM","MAF","SPM","SMR","SVK","SVN","ESP"],
 
 };
@@ -230,9 +355,9 @@ pub const EUR: CurrencyCode = CurrencyCode {
 pub const ALL: CurrencyCode = CurrencyCode {
     name: "Lek",
     code: "ALL",
-    numeric: 008,
+    numeric: 8,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["ALB"],
 
 };
@@ -241,9 +366,9 @@ pub const ALL: CurrencyCode = CurrencyCode {
 pub const DZD: CurrencyCode = CurrencyCode {
     name: "Algerian Dinar",
     code: "DZD",
-    numeric: 012,
+    numeric: 12,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["DZA"],
 
 };
@@ -254,7 +379,7 @@ pub const USD: CurrencyCode = CurrencyCode {
     code: "USD",
     numeric: 840,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["ASM","BES","IOT","VGB","ECU","SLV","GUM","HTI","MHL","FSM","MNP","PLW","PAN","PRI","TLS","TCA","UMI","USA","VIR"],
 
 };
@@ -265,7 +390,7 @@ pub const AOA: CurrencyCode = CurrencyCode {
     code: "AOA",
     numeric: 973,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["AGO"],
 
 };
@@ -276,7 +401,7 @@ pub const XCD: CurrencyCode = CurrencyCode {
     code: "XCD",
     numeric: 951,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["AIA","ATG","DMA","GRD","MSR","KNA","LCA","VCT"],
 
 };
@@ -287,7 +412,7 @@ pub const XAD: CurrencyCode = CurrencyCode {
     code: "XAD",
     numeric: 396,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &[],
 
 };
@@ -296,9 +421,9 @@ pub const XAD: CurrencyCode = CurrencyCode {
 pub const ARS: CurrencyCode = CurrencyCode {
     name: "Argentine Peso",
     code: "ARS",
-    numeric: 032,
+    numeric: 32,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["ARG"],
 
 };
@@ -307,9 +432,9 @@ pub const ARS: CurrencyCode = CurrencyCode {
 pub const AMD: CurrencyCode = CurrencyCode {
     name: "Armenian Dram",
     code: "AMD",
-    numeric: 051,
+    numeric: 51,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["ARM"],
 
 };
@@ -320,7 +445,7 @@ pub const AWG: CurrencyCode = CurrencyCode {
     code: "AWG",
     numeric: 533,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["ABW"],
 
 };
@@ -329,9 +454,9 @@ pub const AWG: CurrencyCode = CurrencyCode {
 pub const AUD: CurrencyCode = CurrencyCode {
     name: "Australian Dollar",
     code: "AUD",
-    numeric: 036,
+    numeric: 36,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["AUS","CXR","CCK","HMD","KIR","NRU","NFK","TUV"],
 
 };
@@ -342,7 +467,7 @@ pub const AZN: CurrencyCode = CurrencyCode {
     code: "AZN",
     numeric: 944,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["AZE"],
 
 };
@@ -351,9 +476,9 @@ pub const AZN: CurrencyCode = CurrencyCode {
 pub const BSD: CurrencyCode = CurrencyCode {
     name: "Bahamian Dollar",
     code: "BSD",
-    numeric: 044,
+    numeric: 44,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["BHS"],
 
 };
@@ -362,9 +487,9 @@ pub const BSD: CurrencyCode = CurrencyCode {
 pub const BHD: CurrencyCode = CurrencyCode {
     name: "Bahraini Dinar",
     code: "BHD",
-    numeric: 048,
+    numeric: 48,
     unit: 3,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["BHR"],
 
 };
@@ -373,9 +498,9 @@ pub const BHD: CurrencyCode = CurrencyCode {
 pub const BDT: CurrencyCode = CurrencyCode {
     name: "Taka",
     code: "BDT",
-    numeric: 050,
+    numeric: 50,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["BGD"],
 
 };
@@ -384,9 +509,9 @@ pub const BDT: CurrencyCode = CurrencyCode {
 pub const BBD: CurrencyCode = CurrencyCode {
     name: "Barbados Dollar",
     code: "BBD",
-    numeric: 052,
+    numeric: 52,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["BRB"],
 
 };
@@ -397,7 +522,7 @@ pub const BYN: CurrencyCode = CurrencyCode {
     code: "BYN",
     numeric: 933,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["BLR"],
 
 };
@@ -406,9 +531,9 @@ pub const BYN: CurrencyCode = CurrencyCode {
 pub const BZD: CurrencyCode = CurrencyCode {
     name: "Belize Dollar",
     code: "BZD",
-    numeric: 084,
+    numeric: 84,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["BLZ"],
 
 };
@@ -419,7 +544,7 @@ pub const XOF: CurrencyCode = CurrencyCode {
     code: "XOF",
     numeric: 952,
     unit: 0,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["BEN","BFA","GNB","CIV","MLI","NER","SEN","TGO"],
 
 };
@@ -428,9 +553,9 @@ pub const XOF: CurrencyCode = CurrencyCode {
 pub const BMD: CurrencyCode = CurrencyCode {
     name: "Bermudian Dollar",
     code: "BMD",
-    numeric: 060,
+    numeric: 60,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["BMU"],
 
 };
@@ -441,7 +566,7 @@ pub const INR: CurrencyCode = CurrencyCode {
     code: "INR",
     numeric: 356,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["BTN","IND"],
 
 };
@@ -450,9 +575,9 @@ pub const INR: CurrencyCode = CurrencyCode {
 pub const BTN: CurrencyCode = CurrencyCode {
     name: "Ngultrum",
     code: "BTN",
-    numeric: 064,
+    numeric: 64,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["BTN"],
 
 };
@@ -461,9 +586,9 @@ pub const BTN: CurrencyCode = CurrencyCode {
 pub const BOB: CurrencyCode = CurrencyCode {
     name: "Boliviano",
     code: "BOB",
-    numeric: 068,
+    numeric: 68,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["BOL"],
 
 };
@@ -474,7 +599,7 @@ pub const BOV: CurrencyCode = CurrencyCode {
     code: "BOV",
     numeric: 984,
     unit: 2,
-    code_type: "funds",
+    code_type: CodeType::Funds,
     countries: &["BOL"],
 
 };
@@ -485,7 +610,7 @@ pub const BAM: CurrencyCode = CurrencyCode {
     code: "BAM",
     numeric: 977,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["BIH"],
 
 };
@@ -494,9 +619,9 @@ pub const BAM: CurrencyCode = CurrencyCode {
 pub const BWP: CurrencyCode = CurrencyCode {
     name: "Pula",
     code: "BWP",
-    numeric: 072,
+    numeric: 72,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["BWA"],
 
 };
@@ -507,7 +632,7 @@ pub const NOK: CurrencyCode = CurrencyCode {
     code: "NOK",
     numeric: 578,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["BVT","NOR","SJM"],
 
 };
@@ -518,7 +643,7 @@ pub const BRL: CurrencyCode = CurrencyCode {
     code: "BRL",
     numeric: 986,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["BRA"],
 
 };
@@ -527,9 +652,9 @@ pub const BRL: CurrencyCode = CurrencyCode {
 pub const BND: CurrencyCode = CurrencyCode {
     name: "Brunei Dollar",
     code: "BND",
-    numeric: 096,
+    numeric: 96,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["BRN"],
 
 };
@@ -540,7 +665,7 @@ pub const BIF: CurrencyCode = CurrencyCode {
     code: "BIF",
     numeric: 108,
     unit: 0,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["BDI"],
 
 };
@@ -551,7 +676,7 @@ pub const CVE: CurrencyCode = CurrencyCode {
     code: "CVE",
     numeric: 132,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["CPV"],
 
 };
@@ -562,7 +687,7 @@ pub const KHR: CurrencyCode = CurrencyCode {
     code: "KHR",
     numeric: 116,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["KHM"],
 
 };
@@ -573,7 +698,7 @@ pub const XAF: CurrencyCode = CurrencyCode {
     code: "XAF",
     numeric: 950,
     unit: 0,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["CMR","CAF","TCD","COG","GNQ","GAB"],
 
 };
@@ -584,7 +709,7 @@ pub const CAD: CurrencyCode = CurrencyCode {
     code: "CAD",
     numeric: 124,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["CAN"],
 
 };
@@ -595,7 +720,7 @@ pub const KYD: CurrencyCode = CurrencyCode {
     code: "KYD",
     numeric: 136,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["CYM"],
 
 };
@@ -606,7 +731,7 @@ pub const CLP: CurrencyCode = CurrencyCode {
     code: "CLP",
     numeric: 152,
     unit: 0,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["CHL"],
 
 };
@@ -617,7 +742,7 @@ pub const CLF: CurrencyCode = CurrencyCode {
     code: "CLF",
     numeric: 990,
     unit: 4,
-    code_type: "funds",
+    code_type: CodeType::Funds,
     countries: &["CHL"],
 
 };
@@ -628,7 +753,7 @@ pub const CNY: CurrencyCode = CurrencyCode {
     code: "CNY",
     numeric: 156,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["CHN"],
 
 };
@@ -639,7 +764,7 @@ pub const COP: CurrencyCode = CurrencyCode {
     code: "COP",
     numeric: 170,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["COL"],
 
 };
@@ -650,7 +775,7 @@ pub const COU: CurrencyCode = CurrencyCode {
     code: "COU",
     numeric: 970,
     unit: 2,
-    code_type: "funds",
+    code_type: CodeType::Funds,
     countries: &["COL"],
 
 };
@@ -661,7 +786,7 @@ pub const KMF: CurrencyCode = CurrencyCode {
     code: "KMF",
     numeric: 174,
     unit: 0,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["COM"],
 
 };
@@ -672,7 +797,7 @@ pub const CDF: CurrencyCode = CurrencyCode {
     code: "CDF",
     numeric: 976,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["COD"],
 
 };
@@ -683,7 +808,7 @@ pub const NZD: CurrencyCode = CurrencyCode {
     code: "NZD",
     numeric: 554,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["COK","NZL","NIU","PCN","TKL"],
 
 };
@@ -694,7 +819,7 @@ pub const CRC: CurrencyCode = CurrencyCode {
     code: "CRC",
     numeric: 188,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["CRI"],
 
 };
@@ -705,7 +830,7 @@ pub const CUP: CurrencyCode = CurrencyCode {
     code: "CUP",
     numeric: 192,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["CUB"],
 
 };
@@ -716,7 +841,7 @@ pub const XCG: CurrencyCode = CurrencyCode {
     code: "XCG",
     numeric: 532,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["CUW","SXM"],
 
 };
@@ -727,7 +852,7 @@ pub const CZK: CurrencyCode = CurrencyCode {
     code: "CZK",
     numeric: 203,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["CZE"],
 
 };
@@ -738,7 +863,7 @@ pub const DKK: CurrencyCode = CurrencyCode {
     code: "DKK",
     numeric: 208,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["DNK","FRO","GRL"],
 
 };
@@ -749,7 +874,7 @@ pub const DJF: CurrencyCode = CurrencyCode {
     code: "DJF",
     numeric: 262,
     unit: 0,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["DJI"],
 
 };
@@ -760,7 +885,7 @@ pub const DOP: CurrencyCode = CurrencyCode {
     code: "DOP",
     numeric: 214,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["DOM"],
 
 };
@@ -771,7 +896,7 @@ pub const EGP: CurrencyCode = CurrencyCode {
     code: "EGP",
     numeric: 818,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["EGY"],
 
 };
@@ -782,7 +907,7 @@ pub const SVC: CurrencyCode = CurrencyCode {
     code: "SVC",
     numeric: 222,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["SLV"],
 
 };
@@ -793,7 +918,7 @@ pub const ERN: CurrencyCode = CurrencyCode {
     code: "ERN",
     numeric: 232,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["ERI"],
 
 };
@@ -804,7 +929,7 @@ pub const SZL: CurrencyCode = CurrencyCode {
     code: "SZL",
     numeric: 748,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["SWZ"],
 
 };
@@ -815,7 +940,7 @@ pub const ETB: CurrencyCode = CurrencyCode {
     code: "ETB",
     numeric: 230,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["ETH"],
 
 };
@@ -826,7 +951,7 @@ pub const FKP: CurrencyCode = CurrencyCode {
     code: "FKP",
     numeric: 238,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["FLK"],
 
 };
@@ -837,7 +962,7 @@ pub const FJD: CurrencyCode = CurrencyCode {
     code: "FJD",
     numeric: 242,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["FJI"],
 
 };
@@ -848,7 +973,7 @@ pub const XPF: CurrencyCode = CurrencyCode {
     code: "XPF",
     numeric: 953,
     unit: 0,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["PYF","NCL","WLF"],
 
 };
@@ -859,7 +984,7 @@ pub const GMD: CurrencyCode = CurrencyCode {
     code: "GMD",
     numeric: 270,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["GMB"],
 
 };
@@ -870,7 +995,7 @@ pub const GEL: CurrencyCode = CurrencyCode {
     code: "GEL",
     numeric: 981,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["GEO"],
 
 };
@@ -881,7 +1006,7 @@ pub const GHS: CurrencyCode = CurrencyCode {
     code: "GHS",
     numeric: 936,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["GHA"],
 
 };
@@ -892,7 +1017,7 @@ pub const GIP: CurrencyCode = CurrencyCode {
     code: "GIP",
     numeric: 292,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["GIB"],
 
 };
@@ -903,7 +1028,7 @@ pub const GTQ: CurrencyCode = CurrencyCode {
     code: "GTQ",
     numeric: 320,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["GTM"],
 
 };
@@ -914,7 +1039,7 @@ pub const GBP: CurrencyCode = CurrencyCode {
     code: "GBP",
     numeric: 826,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["GGY","IMN","JEY","GBR"],
 
 };
@@ -925,7 +1050,7 @@ pub const GNF: CurrencyCode = CurrencyCode {
     code: "GNF",
     numeric: 324,
     unit: 0,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["GIN"],
 
 };
@@ -936,7 +1061,7 @@ pub const GYD: CurrencyCode = CurrencyCode {
     code: "GYD",
     numeric: 328,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["GUY"],
 
 };
@@ -947,7 +1072,7 @@ pub const HTG: CurrencyCode = CurrencyCode {
     code: "HTG",
     numeric: 332,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["HTI"],
 
 };
@@ -958,7 +1083,7 @@ pub const HNL: CurrencyCode = CurrencyCode {
     code: "HNL",
     numeric: 340,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["HND"],
 
 };
@@ -969,7 +1094,7 @@ pub const HKD: CurrencyCode = CurrencyCode {
     code: "HKD",
     numeric: 344,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["HKG"],
 
 };
@@ -980,7 +1105,7 @@ pub const HUF: CurrencyCode = CurrencyCode {
     code: "HUF",
     numeric: 348,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["HUN"],
 
 };
@@ -991,7 +1116,7 @@ pub const ISK: CurrencyCode = CurrencyCode {
     code: "ISK",
     numeric: 352,
     unit: 0,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["ISL"],
 
 };
@@ -1002,7 +1127,7 @@ pub const IDR: CurrencyCode = CurrencyCode {
     code: "IDR",
     numeric: 360,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["IDN"],
 
 };
@@ -1013,7 +1138,7 @@ pub const XDR: CurrencyCode = CurrencyCode {
     code: "XDR",
     numeric: 960,
     unit: -1,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &[],
 
 };
@@ -1024,7 +1149,7 @@ pub const IRR: CurrencyCode = CurrencyCode {
     code: "IRR",
     numeric: 364,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["IRN"],
 
 };
@@ -1035,7 +1160,7 @@ pub const IQD: CurrencyCode = CurrencyCode {
     code: "IQD",
     numeric: 368,
     unit: 3,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["IRQ"],
 
 };
@@ -1046,7 +1171,7 @@ pub const ILS: CurrencyCode = CurrencyCode {
     code: "ILS",
     numeric: 376,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["ISR"],
 
 };
@@ -1057,7 +1182,7 @@ pub const JMD: CurrencyCode = CurrencyCode {
     code: "JMD",
     numeric: 388,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["JAM"],
 
 };
@@ -1068,7 +1193,7 @@ pub const JPY: CurrencyCode = CurrencyCode {
     code: "JPY",
     numeric: 392,
     unit: 0,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["JPN"],
 
 };
@@ -1079,7 +1204,7 @@ pub const JOD: CurrencyCode = CurrencyCode {
     code: "JOD",
     numeric: 400,
     unit: 3,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["JOR"],
 
 };
@@ -1090,7 +1215,7 @@ pub const KZT: CurrencyCode = CurrencyCode {
     code: "KZT",
     numeric: 398,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["KAZ"],
 
 };
@@ -1101,7 +1226,7 @@ pub const KES: CurrencyCode = CurrencyCode {
     code: "KES",
     numeric: 404,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["KEN"],
 
 };
@@ -1112,7 +1237,7 @@ pub const KPW: CurrencyCode = CurrencyCode {
     code: "KPW",
     numeric: 408,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["PRK"],
 
 };
@@ -1123,7 +1248,7 @@ pub const KRW: CurrencyCode = CurrencyCode {
     code: "KRW",
     numeric: 410,
     unit: 0,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["KOR"],
 
 };
@@ -1134,7 +1259,7 @@ pub const KWD: CurrencyCode = CurrencyCode {
     code: "KWD",
     numeric: 414,
     unit: 3,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["KWT"],
 
 };
@@ -1145,7 +1270,7 @@ pub const KGS: CurrencyCode = CurrencyCode {
     code: "KGS",
     numeric: 417,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["KGZ"],
 
 };
@@ -1156,7 +1281,7 @@ pub const LAK: CurrencyCode = CurrencyCode {
     code: "LAK",
     numeric: 418,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["LAO"],
 
 };
@@ -1167,7 +1292,7 @@ pub const LBP: CurrencyCode = CurrencyCode {
     code: "LBP",
     numeric: 422,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["LBN"],
 
 };
@@ -1178,7 +1303,7 @@ pub const LSL: CurrencyCode = CurrencyCode {
     code: "LSL",
     numeric: 426,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["LSO"],
 
 };
@@ -1189,7 +1314,7 @@ pub const ZAR: CurrencyCode = CurrencyCode {
     code: "ZAR",
     numeric: 710,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["LSO","NAM","ZAF"],
 
 };
@@ -1200,7 +1325,7 @@ pub const LRD: CurrencyCode = CurrencyCode {
     code: "LRD",
     numeric: 430,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["LBR"],
 
 };
@@ -1211,7 +1336,7 @@ pub const LYD: CurrencyCode = CurrencyCode {
     code: "LYD",
     numeric: 434,
     unit: 3,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["LBY"],
 
 };
@@ -1222,7 +1347,7 @@ pub const CHF: CurrencyCode = CurrencyCode {
     code: "CHF",
     numeric: 756,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["LIE","CHE"],
 
 };
@@ -1233,7 +1358,7 @@ pub const MOP: CurrencyCode = CurrencyCode {
     code: "MOP",
     numeric: 446,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["MAC"],
 
 };
@@ -1244,7 +1369,7 @@ pub const MGA: CurrencyCode = CurrencyCode {
     code: "MGA",
     numeric: 969,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["MDG"],
 
 };
@@ -1255,7 +1380,7 @@ pub const MWK: CurrencyCode = CurrencyCode {
     code: "MWK",
     numeric: 454,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["MWI"],
 
 };
@@ -1266,7 +1391,7 @@ pub const MYR: CurrencyCode = CurrencyCode {
     code: "MYR",
     numeric: 458,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["MYS"],
 
 };
@@ -1277,7 +1402,7 @@ pub const MVR: CurrencyCode = CurrencyCode {
     code: "MVR",
     numeric: 462,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["MDV"],
 
 };
@@ -1288,7 +1413,7 @@ pub const MRU: CurrencyCode = CurrencyCode {
     code: "MRU",
     numeric: 929,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["MRT"],
 
 };
@@ -1299,7 +1424,7 @@ pub const MUR: CurrencyCode = CurrencyCode {
     code: "MUR",
     numeric: 480,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["MUS"],
 
 };
@@ -1310,7 +1435,7 @@ pub const XUA: CurrencyCode = CurrencyCode {
     code: "XUA",
     numeric: 965,
     unit: -1,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &[],
 
 };
@@ -1321,7 +1446,7 @@ pub const MXN: CurrencyCode = CurrencyCode {
     code: "MXN",
     numeric: 484,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["MEX"],
 
 };
@@ -1332,7 +1457,7 @@ pub const MXV: CurrencyCode = CurrencyCode {
     code: "MXV",
     numeric: 979,
     unit: 2,
-    code_type: "funds",
+    code_type: CodeType::Funds,
     countries: &["MEX"],
 
 };
@@ -1343,7 +1468,7 @@ pub const MDL: CurrencyCode = CurrencyCode {
     code: "MDL",
     numeric: 498,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["MDA"],
 
 };
@@ -1354,7 +1479,7 @@ pub const MNT: CurrencyCode = CurrencyCode {
     code: "MNT",
     numeric: 496,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["MNG"],
 
 };
@@ -1365,7 +1490,7 @@ pub const MAD: CurrencyCode = CurrencyCode {
     code: "MAD",
     numeric: 504,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["MAR","ESH"],
 
 };
@@ -1376,7 +1501,7 @@ pub const MZN: CurrencyCode = CurrencyCode {
     code: "MZN",
     numeric: 943,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["MOZ"],
 
 };
@@ -1387,7 +1512,7 @@ pub const MMK: CurrencyCode = CurrencyCode {
     code: "MMK",
     numeric: 104,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["MMR"],
 
 };
@@ -1398,7 +1523,7 @@ pub const NAD: CurrencyCode = CurrencyCode {
     code: "NAD",
     numeric: 516,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["NAM"],
 
 };
@@ -1409,7 +1534,7 @@ pub const NPR: CurrencyCode = CurrencyCode {
     code: "NPR",
     numeric: 524,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["NPL"],
 
 };
@@ -1420,7 +1545,7 @@ pub const NIO: CurrencyCode = CurrencyCode {
     code: "NIO",
     numeric: 558,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["NIC"],
 
 };
@@ -1431,7 +1556,7 @@ pub const NGN: CurrencyCode = CurrencyCode {
     code: "NGN",
     numeric: 566,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["NGA"],
 
 };
@@ -1442,7 +1567,7 @@ pub const MKD: CurrencyCode = CurrencyCode {
     code: "MKD",
     numeric: 807,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["MKD"],
 
 };
@@ -1453,7 +1578,7 @@ pub const OMR: CurrencyCode = CurrencyCode {
     code: "OMR",
     numeric: 512,
     unit: 3,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["OMN"],
 
 };
@@ -1464,7 +1589,7 @@ pub const PKR: CurrencyCode = CurrencyCode {
     code: "PKR",
     numeric: 586,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["PAK"],
 
 };
@@ -1475,7 +1600,7 @@ pub const PAB: CurrencyCode = CurrencyCode {
     code: "PAB",
     numeric: 590,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["PAN"],
 
 };
@@ -1486,7 +1611,7 @@ pub const PGK: CurrencyCode = CurrencyCode {
     code: "PGK",
     numeric: 598,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["PNG"],
 
 };
@@ -1497,7 +1622,7 @@ pub const PYG: CurrencyCode = CurrencyCode {
     code: "PYG",
     numeric: 600,
     unit: 0,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["PRY"],
 
 };
@@ -1508,7 +1633,7 @@ pub const PEN: CurrencyCode = CurrencyCode {
     code: "PEN",
     numeric: 604,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["PER"],
 
 };
@@ -1519,7 +1644,7 @@ pub const PHP: CurrencyCode = CurrencyCode {
     code: "PHP",
     numeric: 608,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["PHL"],
 
 };
@@ -1530,7 +1655,7 @@ pub const PLN: CurrencyCode = CurrencyCode {
     code: "PLN",
     numeric: 985,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["POL"],
 
 };
@@ -1541,7 +1666,7 @@ pub const QAR: CurrencyCode = CurrencyCode {
     code: "QAR",
     numeric: 634,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["QAT"],
 
 };
@@ -1552,7 +1677,7 @@ pub const RON: CurrencyCode = CurrencyCode {
     code: "RON",
     numeric: 946,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["ROU"],
 
 };
@@ -1563,7 +1688,7 @@ pub const RUB: CurrencyCode = CurrencyCode {
     code: "RUB",
     numeric: 643,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["RUS"],
 
 };
@@ -1574,7 +1699,7 @@ pub const RWF: CurrencyCode = CurrencyCode {
     code: "RWF",
     numeric: 646,
     unit: 0,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["RWA"],
 
 };
@@ -1585,7 +1710,7 @@ pub const SHP: CurrencyCode = CurrencyCode {
     code: "SHP",
     numeric: 654,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["SHN"],
 
 };
@@ -1596,7 +1721,7 @@ pub const WST: CurrencyCode = CurrencyCode {
     code: "WST",
     numeric: 882,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["WSM"],
 
 };
@@ -1607,7 +1732,7 @@ pub const STN: CurrencyCode = CurrencyCode {
     code: "STN",
     numeric: 930,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["STP"],
 
 };
@@ -1618,7 +1743,7 @@ pub const SAR: CurrencyCode = CurrencyCode {
     code: "SAR",
     numeric: 682,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["SAU"],
 
 };
@@ -1629,7 +1754,7 @@ pub const RSD: CurrencyCode = CurrencyCode {
     code: "RSD",
     numeric: 941,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["SRB"],
 
 };
@@ -1640,7 +1765,7 @@ pub const SCR: CurrencyCode = CurrencyCode {
     code: "SCR",
     numeric: 690,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["SYC"],
 
 };
@@ -1651,7 +1776,7 @@ pub const SLE: CurrencyCode = CurrencyCode {
     code: "SLE",
     numeric: 925,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["SLE"],
 
 };
@@ -1662,7 +1787,7 @@ pub const SGD: CurrencyCode = CurrencyCode {
     code: "SGD",
     numeric: 702,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["SGP"],
 
 };
@@ -1673,7 +1798,7 @@ pub const XSU: CurrencyCode = CurrencyCode {
     code: "XSU",
     numeric: 994,
     unit: -1,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &[],
 
 };
@@ -1682,9 +1807,9 @@ pub const XSU: CurrencyCode = CurrencyCode {
 pub const SBD: CurrencyCode = CurrencyCode {
     name: "Solomon Islands Dollar",
     code: "SBD",
-    numeric: 090,
+    numeric: 90,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["SLB"],
 
 };
@@ -1695,7 +1820,7 @@ pub const SOS: CurrencyCode = CurrencyCode {
     code: "SOS",
     numeric: 706,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["SOM"],
 
 };
@@ -1706,7 +1831,7 @@ pub const SSP: CurrencyCode = CurrencyCode {
     code: "SSP",
     numeric: 728,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["SSD"],
 
 };
@@ -1717,7 +1842,7 @@ pub const LKR: CurrencyCode = CurrencyCode {
     code: "LKR",
     numeric: 144,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["LKA"],
 
 };
@@ -1728,7 +1853,7 @@ pub const SDG: CurrencyCode = CurrencyCode {
     code: "SDG",
     numeric: 938,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["SDN"],
 
 };
@@ -1739,7 +1864,7 @@ pub const SRD: CurrencyCode = CurrencyCode {
     code: "SRD",
     numeric: 968,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["SUR"],
 
 };
@@ -1750,7 +1875,7 @@ pub const SEK: CurrencyCode = CurrencyCode {
     code: "SEK",
     numeric: 752,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["SWE"],
 
 };
@@ -1761,7 +1886,7 @@ pub const CHE: CurrencyCode = CurrencyCode {
     code: "CHE",
     numeric: 947,
     unit: 2,
-    code_type: "funds",
+    code_type: CodeType::Funds,
     countries: &["CHE"],
 
 };
@@ -1772,7 +1897,7 @@ pub const CHW: CurrencyCode = CurrencyCode {
     code: "CHW",
     numeric: 948,
     unit: 2,
-    code_type: "funds",
+    code_type: CodeType::Funds,
     countries: &["CHE"],
 
 };
@@ -1783,7 +1908,7 @@ pub const SYP: CurrencyCode = CurrencyCode {
     code: "SYP",
     numeric: 760,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["SYR"],
 
 };
@@ -1794,7 +1919,7 @@ pub const TWD: CurrencyCode = CurrencyCode {
     code: "TWD",
     numeric: 901,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["TWN"],
 
 };
@@ -1805,7 +1930,7 @@ pub const TJS: CurrencyCode = CurrencyCode {
     code: "TJS",
     numeric: 972,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["TJK"],
 
 };
@@ -1816,7 +1941,7 @@ pub const TZS: CurrencyCode = CurrencyCode {
     code: "TZS",
     numeric: 834,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["TZA"],
 
 };
@@ -1827,7 +1952,7 @@ pub const THB: CurrencyCode = CurrencyCode {
     code: "THB",
     numeric: 764,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["THA"],
 
 };
@@ -1838,7 +1963,7 @@ pub const TOP: CurrencyCode = CurrencyCode {
     code: "TOP",
     numeric: 776,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["TON"],
 
 };
@@ -1849,7 +1974,7 @@ pub const TTD: CurrencyCode = CurrencyCode {
     code: "TTD",
     numeric: 780,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["TTO"],
 
 };
@@ -1860,7 +1985,7 @@ pub const TND: CurrencyCode = CurrencyCode {
     code: "TND",
     numeric: 788,
     unit: 3,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["TUN"],
 
 };
@@ -1871,7 +1996,7 @@ pub const TRY: CurrencyCode = CurrencyCode {
     code: "TRY",
     numeric: 949,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["TUR"],
 
 };
@@ -1882,7 +2007,7 @@ pub const TMT: CurrencyCode = CurrencyCode {
     code: "TMT",
     numeric: 934,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["TKM"],
 
 };
@@ -1893,7 +2018,7 @@ pub const UGX: CurrencyCode = CurrencyCode {
     code: "UGX",
     numeric: 800,
     unit: 0,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["UGA"],
 
 };
@@ -1904,7 +2029,7 @@ pub const UAH: CurrencyCode = CurrencyCode {
     code: "UAH",
     numeric: 980,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["UKR"],
 
 };
@@ -1915,7 +2040,7 @@ pub const AED: CurrencyCode = CurrencyCode {
     code: "AED",
     numeric: 784,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["ARE"],
 
 };
@@ -1926,7 +2051,7 @@ pub const USN: CurrencyCode = CurrencyCode {
     code: "USN",
     numeric: 997,
     unit: 2,
-    code_type: "funds",
+    code_type: CodeType::Funds,
     countries: &[],
 
 };
@@ -1937,7 +2062,7 @@ pub const UYU: CurrencyCode = CurrencyCode {
     code: "UYU",
     numeric: 858,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["URY"],
 
 };
@@ -1948,7 +2073,7 @@ pub const UYI: CurrencyCode = CurrencyCode {
     code: "UYI",
     numeric: 940,
     unit: 0,
-    code_type: "funds",
+    code_type: CodeType::Funds,
     countries: &["URY"],
 
 };
@@ -1959,7 +2084,7 @@ pub const UYW: CurrencyCode = CurrencyCode {
     code: "UYW",
     numeric: 927,
     unit: 4,
-    code_type: "funds",
+    code_type: CodeType::Funds,
     countries: &["URY"],
 
 };
@@ -1970,7 +2095,7 @@ pub const UZS: CurrencyCode = CurrencyCode {
     code: "UZS",
     numeric: 860,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["UZB"],
 
 };
@@ -1981,7 +2106,7 @@ pub const VUV: CurrencyCode = CurrencyCode {
     code: "VUV",
     numeric: 548,
     unit: 0,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["VUT"],
 
 };
@@ -1992,7 +2117,7 @@ pub const VES: CurrencyCode = CurrencyCode {
     code: "VES",
     numeric: 928,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["VEN"],
 
 };
@@ -2003,7 +2128,7 @@ pub const VED: CurrencyCode = CurrencyCode {
     code: "VED",
     numeric: 926,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["VEN"],
 
 };
@@ -2014,7 +2139,7 @@ pub const VND: CurrencyCode = CurrencyCode {
     code: "VND",
     numeric: 704,
     unit: 0,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["VNM"],
 
 };
@@ -2025,7 +2150,7 @@ pub const YER: CurrencyCode = CurrencyCode {
     code: "YER",
     numeric: 886,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["YEM"],
 
 };
@@ -2036,7 +2161,7 @@ pub const ZMW: CurrencyCode = CurrencyCode {
     code: "ZMW",
     numeric: 967,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["ZMB"],
 
 };
@@ -2047,7 +2172,7 @@ pub const ZWG: CurrencyCode = CurrencyCode {
     code: "ZWG",
     numeric: 924,
     unit: 2,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &["ZWE"],
 
 };
@@ -2058,7 +2183,7 @@ pub const XBA: CurrencyCode = CurrencyCode {
     code: "XBA",
     numeric: 955,
     unit: -1,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &[],
 
 };
@@ -2069,7 +2194,7 @@ pub const XBB: CurrencyCode = CurrencyCode {
     code: "XBB",
     numeric: 956,
     unit: -1,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &[],
 
 };
@@ -2080,7 +2205,7 @@ pub const XBC: CurrencyCode = CurrencyCode {
     code: "XBC",
     numeric: 957,
     unit: -1,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &[],
 
 };
@@ -2091,7 +2216,7 @@ pub const XBD: CurrencyCode = CurrencyCode {
     code: "XBD",
     numeric: 958,
     unit: -1,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &[],
 
 };
@@ -2102,7 +2227,7 @@ pub const XTS: CurrencyCode = CurrencyCode {
     code: "XTS",
     numeric: 963,
     unit: -1,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &[],
 
 };
@@ -2113,7 +2238,7 @@ pub const XXX: CurrencyCode = CurrencyCode {
     code: "XXX",
     numeric: 999,
     unit: -1,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &[],
 
 };
@@ -2124,7 +2249,7 @@ pub const XAU: CurrencyCode = CurrencyCode {
     code: "XAU",
     numeric: 959,
     unit: -1,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &[],
 
 };
@@ -2135,7 +2260,7 @@ pub const XPD: CurrencyCode = CurrencyCode {
     code: "XPD",
     numeric: 964,
     unit: -1,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &[],
 
 };
@@ -2146,7 +2271,7 @@ pub const XPT: CurrencyCode = CurrencyCode {
     code: "XPT",
     numeric: 962,
     unit: -1,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &[],
 
 };
@@ -2157,7 +2282,7 @@ pub const XAG: CurrencyCode = CurrencyCode {
     code: "XAG",
     numeric: 961,
     unit: -1,
-    code_type: "currency",
+    code_type: CodeType::Currency,
     countries: &[],
 
 };
@@ -2166,9 +2291,9 @@ pub const XAG: CurrencyCode = CurrencyCode {
 pub const AFA: CurrencyCode = CurrencyCode {
     name: "Afghani",
     code: "AFA",
-    numeric: 004,
+    numeric: 4,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -2179,7 +2304,7 @@ pub const FIM: CurrencyCode = CurrencyCode {
     code: "FIM",
     numeric: 246,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -2188,9 +2313,9 @@ pub const FIM: CurrencyCode = CurrencyCode {
 pub const ALK: CurrencyCode = CurrencyCode {
     name: "Old Lek",
     code: "ALK",
-    numeric: 008,
+    numeric: 8,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -2199,9 +2324,9 @@ pub const ALK: CurrencyCode = CurrencyCode {
 pub const ADP: CurrencyCode = CurrencyCode {
     name: "Andorran Peseta",
     code: "ADP",
-    numeric: 020,
+    numeric: 20,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -2212,7 +2337,7 @@ pub const ESP: CurrencyCode = CurrencyCode {
     code: "ESP",
     numeric: 724,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -2223,7 +2348,7 @@ pub const FRF: CurrencyCode = CurrencyCode {
     code: "FRF",
     numeric: 250,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -2232,9 +2357,9 @@ pub const FRF: CurrencyCode = CurrencyCode {
 pub const AOK: CurrencyCode = CurrencyCode {
     name: "Kwanza",
     code: "AOK",
-    numeric: 024,
+    numeric: 24,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -2243,9 +2368,9 @@ pub const AOK: CurrencyCode = CurrencyCode {
 pub const AON: CurrencyCode = CurrencyCode {
     name: "New Kwanza",
     code: "AON",
-    numeric: 024,
+    numeric: 24,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -2256,7 +2381,7 @@ pub const AOR: CurrencyCode = CurrencyCode {
     code: "AOR",
     numeric: 982,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -2265,9 +2390,9 @@ pub const AOR: CurrencyCode = CurrencyCode {
 pub const ARA: CurrencyCode = CurrencyCode {
     name: "Austral",
     code: "ARA",
-    numeric: 032,
+    numeric: 32,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -2276,9 +2401,9 @@ pub const ARA: CurrencyCode = CurrencyCode {
 pub const ARP: CurrencyCode = CurrencyCode {
     name: "Peso Argentino",
     code: "ARP",
-    numeric: 032,
+    numeric: 32,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -2287,9 +2412,9 @@ pub const ARP: CurrencyCode = CurrencyCode {
 pub const ARY: CurrencyCode = CurrencyCode {
     name: "Peso",
     code: "ARY",
-    numeric: 032,
+    numeric: 32,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -2300,7 +2425,7 @@ pub const RUR: CurrencyCode = CurrencyCode {
     code: "RUR",
     numeric: 810,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -2309,9 +2434,9 @@ pub const RUR: CurrencyCode = CurrencyCode {
 pub const ATS: CurrencyCode = CurrencyCode {
     name: "Schilling",
     code: "ATS",
-    numeric: 040,
+    numeric: 40,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -2322,7 +2447,7 @@ pub const AYM: CurrencyCode = CurrencyCode {
     code: "AYM",
     numeric: 945,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -2331,9 +2456,9 @@ pub const AYM: CurrencyCode = CurrencyCode {
 pub const AZM: CurrencyCode = CurrencyCode {
     name: "Azerbaijanian Manat",
     code: "AZM",
-    numeric: 031,
+    numeric: 31,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -2344,7 +2469,7 @@ pub const BYB: CurrencyCode = CurrencyCode {
     code: "BYB",
     numeric: 112,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -2355,7 +2480,7 @@ pub const BYR: CurrencyCode = CurrencyCode {
     code: "BYR",
     numeric: 974,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -2366,7 +2491,7 @@ pub const BEC: CurrencyCode = CurrencyCode {
     code: "BEC",
     numeric: 993,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -2375,9 +2500,9 @@ pub const BEC: CurrencyCode = CurrencyCode {
 pub const BEF: CurrencyCode = CurrencyCode {
     name: "Belgian Franc",
     code: "BEF",
-    numeric: 056,
+    numeric: 56,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -2388,7 +2513,7 @@ pub const BEL: CurrencyCode = CurrencyCode {
     code: "BEL",
     numeric: 992,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -2397,9 +2522,9 @@ pub const BEL: CurrencyCode = CurrencyCode {
 pub const BOP: CurrencyCode = CurrencyCode {
     name: "Peso boliviano",
     code: "BOP",
-    numeric: 068,
+    numeric: 68,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -2408,9 +2533,9 @@ pub const BOP: CurrencyCode = CurrencyCode {
 pub const BAD: CurrencyCode = CurrencyCode {
     name: "Dinar",
     code: "BAD",
-    numeric: 070,
+    numeric: 70,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -2419,9 +2544,9 @@ pub const BAD: CurrencyCode = CurrencyCode {
 pub const BRB: CurrencyCode = CurrencyCode {
     name: "Cruzeiro",
     code: "BRB",
-    numeric: 076,
+    numeric: 76,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -2430,9 +2555,9 @@ pub const BRB: CurrencyCode = CurrencyCode {
 pub const BRC: CurrencyCode = CurrencyCode {
     name: "Cruzado",
     code: "BRC",
-    numeric: 076,
+    numeric: 76,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -2441,9 +2566,9 @@ pub const BRC: CurrencyCode = CurrencyCode {
 pub const BRE: CurrencyCode = CurrencyCode {
     name: "Cruzeiro",
     code: "BRE",
-    numeric: 076,
+    numeric: 76,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -2452,9 +2577,9 @@ pub const BRE: CurrencyCode = CurrencyCode {
 pub const BRN: CurrencyCode = CurrencyCode {
     name: "New Cruzado",
     code: "BRN",
-    numeric: 076,
+    numeric: 76,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -2465,7 +2590,7 @@ pub const BRR: CurrencyCode = CurrencyCode {
     code: "BRR",
     numeric: 987,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -2476,7 +2601,7 @@ pub const BGJ: CurrencyCode = CurrencyCode {
     code: "BGJ",
     numeric: 100,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -2487,7 +2612,7 @@ pub const BGK: CurrencyCode = CurrencyCode {
     code: "BGK",
     numeric: 100,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -2498,7 +2623,7 @@ pub const BGL: CurrencyCode = CurrencyCode {
     code: "BGL",
     numeric: 100,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -2509,7 +2634,7 @@ pub const BGN: CurrencyCode = CurrencyCode {
     code: "BGN",
     numeric: 975,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -2520,7 +2645,7 @@ pub const BUK: CurrencyCode = CurrencyCode {
     code: "BUK",
     numeric: 104,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -2531,7 +2656,7 @@ pub const HRD: CurrencyCode = CurrencyCode {
     code: "HRD",
     numeric: 191,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -2542,7 +2667,7 @@ pub const HRK: CurrencyCode = CurrencyCode {
     code: "HRK",
     numeric: 191,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -2553,7 +2678,7 @@ pub const CUC: CurrencyCode = CurrencyCode {
     code: "CUC",
     numeric: 931,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -2564,7 +2689,7 @@ pub const ANG: CurrencyCode = CurrencyCode {
     code: "ANG",
     numeric: 532,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -2575,7 +2700,7 @@ pub const CYP: CurrencyCode = CurrencyCode {
     code: "CYP",
     numeric: 196,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -2586,7 +2711,7 @@ pub const CSJ: CurrencyCode = CurrencyCode {
     code: "CSJ",
     numeric: 203,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -2597,7 +2722,7 @@ pub const CSK: CurrencyCode = CurrencyCode {
     code: "CSK",
     numeric: 200,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -2608,7 +2733,7 @@ pub const ECS: CurrencyCode = CurrencyCode {
     code: "ECS",
     numeric: 218,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -2619,7 +2744,7 @@ pub const ECV: CurrencyCode = CurrencyCode {
     code: "ECV",
     numeric: 983,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -2630,7 +2755,7 @@ pub const GQE: CurrencyCode = CurrencyCode {
     code: "GQE",
     numeric: 226,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -2641,7 +2766,7 @@ pub const EEK: CurrencyCode = CurrencyCode {
     code: "EEK",
     numeric: 233,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -2652,7 +2777,7 @@ pub const XEU: CurrencyCode = CurrencyCode {
     code: "XEU",
     numeric: 954,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -2663,7 +2788,7 @@ pub const GEK: CurrencyCode = CurrencyCode {
     code: "GEK",
     numeric: 268,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -2674,7 +2799,7 @@ pub const DDM: CurrencyCode = CurrencyCode {
     code: "DDM",
     numeric: 278,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -2685,7 +2810,7 @@ pub const DEM: CurrencyCode = CurrencyCode {
     code: "DEM",
     numeric: 276,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -2696,7 +2821,7 @@ pub const GHC: CurrencyCode = CurrencyCode {
     code: "GHC",
     numeric: 288,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -2707,7 +2832,7 @@ pub const GHP: CurrencyCode = CurrencyCode {
     code: "GHP",
     numeric: 939,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -2718,7 +2843,7 @@ pub const GRD: CurrencyCode = CurrencyCode {
     code: "GRD",
     numeric: 300,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -2729,7 +2854,7 @@ pub const GNE: CurrencyCode = CurrencyCode {
     code: "GNE",
     numeric: 324,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -2740,7 +2865,7 @@ pub const GNS: CurrencyCode = CurrencyCode {
     code: "GNS",
     numeric: 324,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -2751,7 +2876,7 @@ pub const GWE: CurrencyCode = CurrencyCode {
     code: "GWE",
     numeric: 624,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -2762,7 +2887,7 @@ pub const GWP: CurrencyCode = CurrencyCode {
     code: "GWP",
     numeric: 624,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -2773,7 +2898,7 @@ pub const ITL: CurrencyCode = CurrencyCode {
     code: "ITL",
     numeric: 380,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -2784,7 +2909,7 @@ pub const ISJ: CurrencyCode = CurrencyCode {
     code: "ISJ",
     numeric: 352,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -2795,7 +2920,7 @@ pub const IEP: CurrencyCode = CurrencyCode {
     code: "IEP",
     numeric: 372,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -2806,7 +2931,7 @@ pub const ILP: CurrencyCode = CurrencyCode {
     code: "ILP",
     numeric: 376,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -2817,7 +2942,7 @@ pub const ILR: CurrencyCode = CurrencyCode {
     code: "ILR",
     numeric: 376,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -2828,7 +2953,7 @@ pub const LAJ: CurrencyCode = CurrencyCode {
     code: "LAJ",
     numeric: 418,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -2839,7 +2964,7 @@ pub const LVL: CurrencyCode = CurrencyCode {
     code: "LVL",
     numeric: 428,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -2850,7 +2975,7 @@ pub const LVR: CurrencyCode = CurrencyCode {
     code: "LVR",
     numeric: 428,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -2861,7 +2986,7 @@ pub const LSM: CurrencyCode = CurrencyCode {
     code: "LSM",
     numeric: 426,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -2872,7 +2997,7 @@ pub const ZAL: CurrencyCode = CurrencyCode {
     code: "ZAL",
     numeric: 991,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -2883,7 +3008,7 @@ pub const LTL: CurrencyCode = CurrencyCode {
     code: "LTL",
     numeric: 440,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -2894,7 +3019,7 @@ pub const LTT: CurrencyCode = CurrencyCode {
     code: "LTT",
     numeric: 440,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -2905,7 +3030,7 @@ pub const LUC: CurrencyCode = CurrencyCode {
     code: "LUC",
     numeric: 989,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -2916,7 +3041,7 @@ pub const LUF: CurrencyCode = CurrencyCode {
     code: "LUF",
     numeric: 442,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -2927,7 +3052,7 @@ pub const LUL: CurrencyCode = CurrencyCode {
     code: "LUL",
     numeric: 988,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -2938,7 +3063,7 @@ pub const MGF: CurrencyCode = CurrencyCode {
     code: "MGF",
     numeric: 450,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -2949,7 +3074,7 @@ pub const MVQ: CurrencyCode = CurrencyCode {
     code: "MVQ",
     numeric: 462,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -2960,7 +3085,7 @@ pub const MLF: CurrencyCode = CurrencyCode {
     code: "MLF",
     numeric: 466,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -2971,7 +3096,7 @@ pub const MTL: CurrencyCode = CurrencyCode {
     code: "MTL",
     numeric: 470,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -2982,7 +3107,7 @@ pub const MTP: CurrencyCode = CurrencyCode {
     code: "MTP",
     numeric: 470,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -2993,7 +3118,7 @@ pub const MRO: CurrencyCode = CurrencyCode {
     code: "MRO",
     numeric: 478,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -3004,7 +3129,7 @@ pub const MXP: CurrencyCode = CurrencyCode {
     code: "MXP",
     numeric: 484,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -3015,7 +3140,7 @@ pub const MZE: CurrencyCode = CurrencyCode {
     code: "MZE",
     numeric: 508,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -3026,7 +3151,7 @@ pub const MZM: CurrencyCode = CurrencyCode {
     code: "MZM",
     numeric: 508,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -3037,7 +3162,7 @@ pub const NLG: CurrencyCode = CurrencyCode {
     code: "NLG",
     numeric: 528,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -3048,7 +3173,7 @@ pub const NIC: CurrencyCode = CurrencyCode {
     code: "NIC",
     numeric: 558,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -3059,7 +3184,7 @@ pub const PEH: CurrencyCode = CurrencyCode {
     code: "PEH",
     numeric: 604,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -3070,7 +3195,7 @@ pub const PEI: CurrencyCode = CurrencyCode {
     code: "PEI",
     numeric: 604,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -3081,7 +3206,7 @@ pub const PES: CurrencyCode = CurrencyCode {
     code: "PES",
     numeric: 604,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -3092,7 +3217,7 @@ pub const PLZ: CurrencyCode = CurrencyCode {
     code: "PLZ",
     numeric: 616,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -3103,7 +3228,7 @@ pub const PTE: CurrencyCode = CurrencyCode {
     code: "PTE",
     numeric: 620,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -3114,7 +3239,7 @@ pub const ROK: CurrencyCode = CurrencyCode {
     code: "ROK",
     numeric: 642,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -3125,7 +3250,7 @@ pub const ROL: CurrencyCode = CurrencyCode {
     code: "ROL",
     numeric: 642,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -3136,7 +3261,7 @@ pub const STD: CurrencyCode = CurrencyCode {
     code: "STD",
     numeric: 678,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -3147,7 +3272,7 @@ pub const CSD: CurrencyCode = CurrencyCode {
     code: "CSD",
     numeric: 891,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -3158,7 +3283,7 @@ pub const SLL: CurrencyCode = CurrencyCode {
     code: "SLL",
     numeric: 694,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -3169,7 +3294,7 @@ pub const SKK: CurrencyCode = CurrencyCode {
     code: "SKK",
     numeric: 703,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -3180,7 +3305,7 @@ pub const SIT: CurrencyCode = CurrencyCode {
     code: "SIT",
     numeric: 705,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -3191,7 +3316,7 @@ pub const RHD: CurrencyCode = CurrencyCode {
     code: "RHD",
     numeric: 716,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -3202,7 +3327,7 @@ pub const ESA: CurrencyCode = CurrencyCode {
     code: "ESA",
     numeric: 996,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -3213,7 +3338,7 @@ pub const ESB: CurrencyCode = CurrencyCode {
     code: "ESB",
     numeric: 995,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -3224,7 +3349,7 @@ pub const SDD: CurrencyCode = CurrencyCode {
     code: "SDD",
     numeric: 736,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -3235,7 +3360,7 @@ pub const SDP: CurrencyCode = CurrencyCode {
     code: "SDP",
     numeric: 736,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -3246,7 +3371,7 @@ pub const SRG: CurrencyCode = CurrencyCode {
     code: "SRG",
     numeric: 740,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -3257,7 +3382,7 @@ pub const CHC: CurrencyCode = CurrencyCode {
     code: "CHC",
     numeric: 948,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -3268,7 +3393,7 @@ pub const TJR: CurrencyCode = CurrencyCode {
     code: "TJR",
     numeric: 762,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -3279,7 +3404,7 @@ pub const TPE: CurrencyCode = CurrencyCode {
     code: "TPE",
     numeric: 626,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -3290,7 +3415,7 @@ pub const TRL: CurrencyCode = CurrencyCode {
     code: "TRL",
     numeric: 792,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -3301,7 +3426,7 @@ pub const TMM: CurrencyCode = CurrencyCode {
     code: "TMM",
     numeric: 795,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -3312,7 +3437,7 @@ pub const UGS: CurrencyCode = CurrencyCode {
     code: "UGS",
     numeric: 800,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -3323,7 +3448,7 @@ pub const UGW: CurrencyCode = CurrencyCode {
     code: "UGW",
     numeric: 800,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -3334,7 +3459,7 @@ pub const UAK: CurrencyCode = CurrencyCode {
     code: "UAK",
     numeric: 804,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -3345,7 +3470,7 @@ pub const SUR: CurrencyCode = CurrencyCode {
     code: "SUR",
     numeric: 810,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -3356,7 +3481,7 @@ pub const USS: CurrencyCode = CurrencyCode {
     code: "USS",
     numeric: 998,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -3367,7 +3492,7 @@ pub const UYN: CurrencyCode = CurrencyCode {
     code: "UYN",
     numeric: 858,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -3378,7 +3503,7 @@ pub const UYP: CurrencyCode = CurrencyCode {
     code: "UYP",
     numeric: 858,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -3389,7 +3514,7 @@ pub const VEB: CurrencyCode = CurrencyCode {
     code: "VEB",
     numeric: 862,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -3400,7 +3525,7 @@ pub const VEF: CurrencyCode = CurrencyCode {
     code: "VEF",
     numeric: 937,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -3411,7 +3536,7 @@ pub const VNC: CurrencyCode = CurrencyCode {
     code: "VNC",
     numeric: 704,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -3422,7 +3547,7 @@ pub const YDD: CurrencyCode = CurrencyCode {
     code: "YDD",
     numeric: 720,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -3433,7 +3558,7 @@ pub const YUD: CurrencyCode = CurrencyCode {
     code: "YUD",
     numeric: 890,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -3444,7 +3569,7 @@ pub const YUM: CurrencyCode = CurrencyCode {
     code: "YUM",
     numeric: 891,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -3455,7 +3580,7 @@ pub const YUN: CurrencyCode = CurrencyCode {
     code: "YUN",
     numeric: 890,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -3466,7 +3591,7 @@ pub const ZRN: CurrencyCode = CurrencyCode {
     code: "ZRN",
     numeric: 180,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -3477,7 +3602,7 @@ pub const ZRZ: CurrencyCode = CurrencyCode {
     code: "ZRZ",
     numeric: 180,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -3488,7 +3613,7 @@ pub const ZMK: CurrencyCode = CurrencyCode {
     code: "ZMK",
     numeric: 894,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -3499,7 +3624,7 @@ pub const ZWC: CurrencyCode = CurrencyCode {
     code: "ZWC",
     numeric: 716,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -3510,7 +3635,7 @@ pub const ZWD: CurrencyCode = CurrencyCode {
     code: "ZWD",
     numeric: 716,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -3521,7 +3646,7 @@ pub const ZWN: CurrencyCode = CurrencyCode {
     code: "ZWN",
     numeric: 942,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -3532,7 +3657,7 @@ pub const ZWR: CurrencyCode = CurrencyCode {
     code: "ZWR",
     numeric: 935,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -3543,7 +3668,7 @@ pub const ZWL: CurrencyCode = CurrencyCode {
     code: "ZWL",
     numeric: 932,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -3554,7 +3679,7 @@ pub const XFO: CurrencyCode = CurrencyCode {
     code: "XFO",
     numeric: -1,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -3565,7 +3690,7 @@ pub const XRE: CurrencyCode = CurrencyCode {
     code: "XRE",
     numeric: -1,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -3576,7 +3701,7 @@ pub const XFU: CurrencyCode = CurrencyCode {
     code: "XFU",
     numeric: -1,
     unit: -1,
-    code_type: "historic",
+    code_type: CodeType::Historic,
     countries: &[],
 
 };
@@ -4413,6 +4538,16 @@ pub const COUNTRY_MAP: Map<&str,&'static [&'static CurrencyCode]> = phf_map! {
 };
 
 
+///ISO 3166-1 alpha-3 codes of countries without a currency
+pub const NO_CURRENCY_COUNTRY: & [ & str] = &[
+    
+"ATA",
+"SGS",
+"PSE",
+
+];
+
+
 ///ALL names
 pub const ALL_NAME: & [ & str] = &[
     
@@ -5374,34 +5509,34 @@ pub const ALL_NUMERIC: & [i32] = &[
 
 971,
 978,
-008,
-012,
+8,
+12,
 840,
 973,
 951,
 396,
-032,
-051,
+32,
+51,
 533,
-036,
+36,
 944,
-044,
-048,
-050,
-052,
+44,
+48,
+50,
+52,
 933,
-084,
+84,
 952,
-060,
+60,
 356,
-064,
-068,
+64,
+68,
 984,
 977,
-072,
+72,
 578,
 986,
-096,
+96,
 108,
 132,
 116,
@@ -5506,7 +5641,7 @@ pub const ALL_NUMERIC: & [i32] = &[
 925,
 702,
 994,
-090,
+90,
 706,
 728,
 144,
@@ -5550,24 +5685,24 @@ pub const ALL_NUMERIC: & [i32] = &[
 964,
 962,
 961,
-004,
+4,
 246,
-020,
+20,
 724,
 250,
-024,
+24,
 982,
 810,
-040,
+40,
 945,
-031,
+31,
 112,
 974,
 993,
-056,
+56,
 992,
-070,
-076,
+70,
+76,
 987,
 100,
 975,
@@ -6210,3 +6345,1702 @@ XFU,
 
 ];
 
+///Every ISO 4217 code as an enum variant, in `ALL_CODES` order
+#[allow(clippy::upper_case_acronyms)]
+#[non_exhaustive]
+#[repr(u16)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Currency {
+
+    ///Afghani
+    AFN,
+    ///Euro
+    EUR,
+    ///Lek
+    ALL,
+    ///Algerian Dinar
+    DZD,
+    ///US Dollar
+    USD,
+    ///Kwanza
+    AOA,
+    ///East Caribbean Dollar
+    XCD,
+    ///Arab Accounting Dinar
+    XAD,
+    ///Argentine Peso
+    ARS,
+    ///Armenian Dram
+    AMD,
+    ///Aruban Florin
+    AWG,
+    ///Australian Dollar
+    AUD,
+    ///Azerbaijan Manat
+    AZN,
+    ///Bahamian Dollar
+    BSD,
+    ///Bahraini Dinar
+    BHD,
+    ///Taka
+    BDT,
+    ///Barbados Dollar
+    BBD,
+    ///Belarusian Ruble
+    BYN,
+    ///Belize Dollar
+    BZD,
+    ///CFA Franc BCEAO
+    XOF,
+    ///Bermudian Dollar
+    BMD,
+    ///Indian Rupee
+    INR,
+    ///Ngultrum
+    BTN,
+    ///Boliviano
+    BOB,
+    ///Mvdol
+    BOV,
+    ///Convertible Mark
+    BAM,
+    ///Pula
+    BWP,
+    ///Norwegian Krone
+    NOK,
+    ///Brazilian Real
+    BRL,
+    ///Brunei Dollar
+    BND,
+    ///Burundi Franc
+    BIF,
+    ///Cabo Verde Escudo
+    CVE,
+    ///Riel
+    KHR,
+    ///CFA Franc BEAC
+    XAF,
+    ///Canadian Dollar
+    CAD,
+    ///Cayman Islands Dollar
+    KYD,
+    ///Chilean Peso
+    CLP,
+    ///Unidad de Fomento
+    CLF,
+    ///Yuan Renminbi
+    CNY,
+    ///Colombian Peso
+    COP,
+    ///Unidad de Valor Real
+    COU,
+    ///Comorian Franc 
+    KMF,
+    ///Congolese Franc
+    CDF,
+    ///New Zealand Dollar
+    NZD,
+    ///Costa Rican Colon
+    CRC,
+    ///Cuban Peso
+    CUP,
+    ///Caribbean Guilder
+    XCG,
+    ///Czech Koruna
+    CZK,
+    ///Danish Krone
+    DKK,
+    ///Djibouti Franc
+    DJF,
+    ///Dominican Peso
+    DOP,
+    ///Egyptian Pound
+    EGP,
+    ///El Salvador Colon
+    SVC,
+    ///Nakfa
+    ERN,
+    ///Lilangeni
+    SZL,
+    ///Ethiopian Birr
+    ETB,
+    ///Falkland Islands Pound
+    FKP,
+    ///Fiji Dollar
+    FJD,
+    ///CFP Franc
+    XPF,
+    ///Dalasi
+    GMD,
+    ///Lari
+    GEL,
+    ///Ghana Cedi
+    GHS,
+    ///Gibraltar Pound
+    GIP,
+    ///Quetzal
+    GTQ,
+    ///Pound Sterling
+    GBP,
+    ///Guinean Franc
+    GNF,
+    ///Guyana Dollar
+    GYD,
+    ///Gourde
+    HTG,
+    ///Lempira
+    HNL,
+    ///Hong Kong Dollar
+    HKD,
+    ///Forint
+    HUF,
+    ///Iceland Krona
+    ISK,
+    ///Rupiah
+    IDR,
+    ///SDR (Special Drawing Right)
+    XDR,
+    ///Iranian Rial
+    IRR,
+    ///Iraqi Dinar
+    IQD,
+    ///New Israeli Sheqel
+    ILS,
+    ///Jamaican Dollar
+    JMD,
+    ///Yen
+    JPY,
+    ///Jordanian Dinar
+    JOD,
+    ///Tenge
+    KZT,
+    ///Kenyan Shilling
+    KES,
+    ///North Korean Won
+    KPW,
+    ///Won
+    KRW,
+    ///Kuwaiti Dinar
+    KWD,
+    ///Som
+    KGS,
+    ///Lao Kip
+    LAK,
+    ///Lebanese Pound
+    LBP,
+    ///Loti
+    LSL,
+    ///Rand
+    ZAR,
+    ///Liberian Dollar
+    LRD,
+    ///Libyan Dinar
+    LYD,
+    ///Swiss Franc
+    CHF,
+    ///Pataca
+    MOP,
+    ///Malagasy Ariary
+    MGA,
+    ///Malawi Kwacha
+    MWK,
+    ///Malaysian Ringgit
+    MYR,
+    ///Rufiyaa
+    MVR,
+    ///Ouguiya
+    MRU,
+    ///Mauritius Rupee
+    MUR,
+    ///ADB Unit of Account
+    XUA,
+    ///Mexican Peso
+    MXN,
+    ///Mexican Unidad de Inversion (UDI)
+    MXV,
+    ///Moldovan Leu
+    MDL,
+    ///Tugrik
+    MNT,
+    ///Moroccan Dirham
+    MAD,
+    ///Mozambique Metical
+    MZN,
+    ///Kyat
+    MMK,
+    ///Namibia Dollar
+    NAD,
+    ///Nepalese Rupee
+    NPR,
+    ///Cordoba Oro
+    NIO,
+    ///Naira
+    NGN,
+    ///Denar
+    MKD,
+    ///Rial Omani
+    OMR,
+    ///Pakistan Rupee
+    PKR,
+    ///Balboa
+    PAB,
+    ///Kina
+    PGK,
+    ///Guarani
+    PYG,
+    ///Sol
+    PEN,
+    ///Philippine Peso
+    PHP,
+    ///Zloty
+    PLN,
+    ///Qatari Rial
+    QAR,
+    ///Romanian Leu
+    RON,
+    ///Russian Ruble
+    RUB,
+    ///Rwanda Franc
+    RWF,
+    ///Saint Helena Pound
+    SHP,
+    ///Tala
+    WST,
+    ///Dobra
+    STN,
+    ///Saudi Riyal
+    SAR,
+    ///Serbian Dinar
+    RSD,
+    ///Seychelles Rupee
+    SCR,
+    ///Leone
+    SLE,
+    ///Singapore Dollar
+    SGD,
+    ///Sucre
+    XSU,
+    ///Solomon Islands Dollar
+    SBD,
+    ///Somali Shilling
+    SOS,
+    ///South Sudanese Pound
+    SSP,
+    ///Sri Lanka Rupee
+    LKR,
+    ///Sudanese Pound
+    SDG,
+    ///Surinam Dollar
+    SRD,
+    ///Swedish Krona
+    SEK,
+    ///WIR Euro
+    CHE,
+    ///WIR Franc
+    CHW,
+    ///Syrian Pound
+    SYP,
+    ///New Taiwan Dollar
+    TWD,
+    ///Somoni
+    TJS,
+    ///Tanzanian Shilling
+    TZS,
+    ///Baht
+    THB,
+    ///Pa’anga
+    TOP,
+    ///Trinidad and Tobago Dollar
+    TTD,
+    ///Tunisian Dinar
+    TND,
+    ///Turkish Lira
+    TRY,
+    ///Turkmenistan New Manat
+    TMT,
+    ///Uganda Shilling
+    UGX,
+    ///Hryvnia
+    UAH,
+    ///UAE Dirham
+    AED,
+    ///US Dollar (Next day)
+    USN,
+    ///Peso Uruguayo
+    UYU,
+    ///Uruguay Peso en Unidades Indexadas (UI)
+    UYI,
+    ///Unidad Previsional
+    UYW,
+    ///Uzbekistan Sum
+    UZS,
+    ///Vatu
+    VUV,
+    ///Bolívar Soberano
+    VES,
+    ///Bolívar Soberano
+    VED,
+    ///Dong
+    VND,
+    ///Yemeni Rial
+    YER,
+    ///Zambian Kwacha
+    ZMW,
+    ///Zimbabwe Gold
+    ZWG,
+    ///Bond Markets Unit European Composite Unit (EURCO)
+    XBA,
+    ///Bond Markets Unit European Monetary Unit (E.M.U.-6)
+    XBB,
+    ///Bond Markets Unit European Unit of Account 9 (E.U.A.-9)
+    XBC,
+    ///Bond Markets Unit European Unit of Account 17 (E.U.A.-17)
+    XBD,
+    ///Codes specifically reserved for testing purposes
+    XTS,
+    ///The codes assigned for transactions where no currency is involved
+    XXX,
+    ///Gold
+    XAU,
+    ///Palladium
+    XPD,
+    ///Platinum
+    XPT,
+    ///Silver
+    XAG,
+    ///Afghani
+    AFA,
+    ///Markka
+    FIM,
+    ///Old Lek
+    ALK,
+    ///Andorran Peseta
+    ADP,
+    ///Spanish Peseta
+    ESP,
+    ///French Franc
+    FRF,
+    ///Kwanza
+    AOK,
+    ///New Kwanza
+    AON,
+    ///Kwanza Reajustado
+    AOR,
+    ///Austral
+    ARA,
+    ///Peso Argentino
+    ARP,
+    ///Peso
+    ARY,
+    ///Russian Ruble
+    RUR,
+    ///Schilling
+    ATS,
+    ///Azerbaijan Manat
+    AYM,
+    ///Azerbaijanian Manat
+    AZM,
+    ///Belarusian Ruble
+    BYB,
+    ///Belarusian Ruble
+    BYR,
+    ///Convertible Franc
+    BEC,
+    ///Belgian Franc
+    BEF,
+    ///Financial Franc
+    BEL,
+    ///Peso boliviano
+    BOP,
+    ///Dinar
+    BAD,
+    ///Cruzeiro
+    BRB,
+    ///Cruzado
+    BRC,
+    ///Cruzeiro
+    BRE,
+    ///New Cruzado
+    BRN,
+    ///Cruzeiro Real
+    BRR,
+    ///Lev A/52
+    BGJ,
+    ///Lev A/62
+    BGK,
+    ///Lev
+    BGL,
+    ///Bulgarian Lev
+    BGN,
+    ///Kyat
+    BUK,
+    ///Croatian Dinar
+    HRD,
+    ///Croatian Kuna
+    HRK,
+    ///Peso Convertible
+    CUC,
+    ///Netherlands Antillean Guilder
+    ANG,
+    ///Cyprus Pound
+    CYP,
+    ///Krona A/53
+    CSJ,
+    ///Koruna
+    CSK,
+    ///Sucre
+    ECS,
+    ///Unidad de Valor Constante (UVC)
+    ECV,
+    ///Ekwele
+    GQE,
+    ///Kroon
+    EEK,
+    ///European Currency Unit (E.C.U)
+    XEU,
+    ///Georgian Coupon
+    GEK,
+    ///Mark der DDR
+    DDM,
+    ///Deutsche Mark
+    DEM,
+    ///Cedi
+    GHC,
+    ///Ghana Cedi
+    GHP,
+    ///Drachma
+    GRD,
+    ///Syli
+    GNE,
+    ///Syli
+    GNS,
+    ///Guinea Escudo
+    GWE,
+    ///Guinea-Bissau Peso
+    GWP,
+    ///Italian Lira
+    ITL,
+    ///Old Krona
+    ISJ,
+    ///Irish Pound
+    IEP,
+    ///Pound
+    ILP,
+    ///Old Shekel
+    ILR,
+    ///Pathet Lao Kip
+    LAJ,
+    ///Latvian Lats
+    LVL,
+    ///Latvian Ruble
+    LVR,
+    ///Loti
+    LSM,
+    ///Financial Rand
+    ZAL,
+    ///Lithuanian Litas
+    LTL,
+    ///Talonas
+    LTT,
+    ///Luxembourg Convertible Franc
+    LUC,
+    ///Luxembourg Franc
+    LUF,
+    ///Luxembourg Financial Franc
+    LUL,
+    ///Malagasy Franc
+    MGF,
+    ///Maldive Rupee
+    MVQ,
+    ///Mali Franc
+    MLF,
+    ///Maltese Lira
+    MTL,
+    ///Maltese Pound
+    MTP,
+    ///Ouguiya
+    MRO,
+    ///Mexican Peso
+    MXP,
+    ///Mozambique Escudo
+    MZE,
+    ///Mozambique Metical
+    MZM,
+    ///Netherlands Guilder
+    NLG,
+    ///Cordoba
+    NIC,
+    ///Sol
+    PEH,
+    ///Inti
+    PEI,
+    ///Sol
+    PES,
+    ///Zloty
+    PLZ,
+    ///Portuguese Escudo
+    PTE,
+    ///Leu A/52
+    ROK,
+    ///Old Leu
+    ROL,
+    ///Dobra
+    STD,
+    ///Serbian Dinar
+    CSD,
+    ///Leone
+    SLL,
+    ///Slovak Koruna
+    SKK,
+    ///Tolar
+    SIT,
+    ///Rhodesian Dollar
+    RHD,
+    ///Spanish Peseta
+    ESA,
+    ///"A" Account (convertible Peseta Account)
+    ESB,
+    ///Sudanese Dinar
+    SDD,
+    ///Sudanese Pound
+    SDP,
+    ///Surinam Guilder
+    SRG,
+    ///WIR Franc (for electronic)
+    CHC,
+    ///Tajik Ruble
+    TJR,
+    ///Timor Escudo
+    TPE,
+    ///Old Turkish Lira
+    TRL,
+    ///Turkmenistan Manat
+    TMM,
+    ///Uganda Shilling
+    UGS,
+    ///Old Shilling
+    UGW,
+    ///Karbovanet
+    UAK,
+    ///Rouble
+    SUR,
+    ///US Dollar (Same day)
+    USS,
+    ///Old Uruguay Peso
+    UYN,
+    ///Uruguayan Peso
+    UYP,
+    ///Bolivar
+    VEB,
+    ///Bolivar Fuerte
+    VEF,
+    ///Old Dong
+    VNC,
+    ///Yemeni Dinar
+    YDD,
+    ///New Yugoslavian Dinar
+    YUD,
+    ///New Dinar
+    YUM,
+    ///Yugoslavian Dinar
+    YUN,
+    ///New Zaire
+    ZRN,
+    ///Zaire
+    ZRZ,
+    ///Zambian Kwacha
+    ZMK,
+    ///Rhodesian Dollar
+    ZWC,
+    ///Zimbabwe Dollar (old)
+    ZWD,
+    ///Zimbabwe Dollar (new)
+    ZWN,
+    ///Zimbabwe Dollar
+    ZWR,
+    ///Zimbabwe Dollar
+    ZWL,
+    ///Gold-Franc
+    XFO,
+    ///RINET Funds Code
+    XRE,
+    ///UIC-Franc
+    XFU,
+
+}
+
+
+///ALL the Currency variants
+pub const ALL_CURRENCIES: & [Currency] = &[
+
+Currency::AFN,
+Currency::EUR,
+Currency::ALL,
+Currency::DZD,
+Currency::USD,
+Currency::AOA,
+Currency::XCD,
+Currency::XAD,
+Currency::ARS,
+Currency::AMD,
+Currency::AWG,
+Currency::AUD,
+Currency::AZN,
+Currency::BSD,
+Currency::BHD,
+Currency::BDT,
+Currency::BBD,
+Currency::BYN,
+Currency::BZD,
+Currency::XOF,
+Currency::BMD,
+Currency::INR,
+Currency::BTN,
+Currency::BOB,
+Currency::BOV,
+Currency::BAM,
+Currency::BWP,
+Currency::NOK,
+Currency::BRL,
+Currency::BND,
+Currency::BIF,
+Currency::CVE,
+Currency::KHR,
+Currency::XAF,
+Currency::CAD,
+Currency::KYD,
+Currency::CLP,
+Currency::CLF,
+Currency::CNY,
+Currency::COP,
+Currency::COU,
+Currency::KMF,
+Currency::CDF,
+Currency::NZD,
+Currency::CRC,
+Currency::CUP,
+Currency::XCG,
+Currency::CZK,
+Currency::DKK,
+Currency::DJF,
+Currency::DOP,
+Currency::EGP,
+Currency::SVC,
+Currency::ERN,
+Currency::SZL,
+Currency::ETB,
+Currency::FKP,
+Currency::FJD,
+Currency::XPF,
+Currency::GMD,
+Currency::GEL,
+Currency::GHS,
+Currency::GIP,
+Currency::GTQ,
+Currency::GBP,
+Currency::GNF,
+Currency::GYD,
+Currency::HTG,
+Currency::HNL,
+Currency::HKD,
+Currency::HUF,
+Currency::ISK,
+Currency::IDR,
+Currency::XDR,
+Currency::IRR,
+Currency::IQD,
+Currency::ILS,
+Currency::JMD,
+Currency::JPY,
+Currency::JOD,
+Currency::KZT,
+Currency::KES,
+Currency::KPW,
+Currency::KRW,
+Currency::KWD,
+Currency::KGS,
+Currency::LAK,
+Currency::LBP,
+Currency::LSL,
+Currency::ZAR,
+Currency::LRD,
+Currency::LYD,
+Currency::CHF,
+Currency::MOP,
+Currency::MGA,
+Currency::MWK,
+Currency::MYR,
+Currency::MVR,
+Currency::MRU,
+Currency::MUR,
+Currency::XUA,
+Currency::MXN,
+Currency::MXV,
+Currency::MDL,
+Currency::MNT,
+Currency::MAD,
+Currency::MZN,
+Currency::MMK,
+Currency::NAD,
+Currency::NPR,
+Currency::NIO,
+Currency::NGN,
+Currency::MKD,
+Currency::OMR,
+Currency::PKR,
+Currency::PAB,
+Currency::PGK,
+Currency::PYG,
+Currency::PEN,
+Currency::PHP,
+Currency::PLN,
+Currency::QAR,
+Currency::RON,
+Currency::RUB,
+Currency::RWF,
+Currency::SHP,
+Currency::WST,
+Currency::STN,
+Currency::SAR,
+Currency::RSD,
+Currency::SCR,
+Currency::SLE,
+Currency::SGD,
+Currency::XSU,
+Currency::SBD,
+Currency::SOS,
+Currency::SSP,
+Currency::LKR,
+Currency::SDG,
+Currency::SRD,
+Currency::SEK,
+Currency::CHE,
+Currency::CHW,
+Currency::SYP,
+Currency::TWD,
+Currency::TJS,
+Currency::TZS,
+Currency::THB,
+Currency::TOP,
+Currency::TTD,
+Currency::TND,
+Currency::TRY,
+Currency::TMT,
+Currency::UGX,
+Currency::UAH,
+Currency::AED,
+Currency::USN,
+Currency::UYU,
+Currency::UYI,
+Currency::UYW,
+Currency::UZS,
+Currency::VUV,
+Currency::VES,
+Currency::VED,
+Currency::VND,
+Currency::YER,
+Currency::ZMW,
+Currency::ZWG,
+Currency::XBA,
+Currency::XBB,
+Currency::XBC,
+Currency::XBD,
+Currency::XTS,
+Currency::XXX,
+Currency::XAU,
+Currency::XPD,
+Currency::XPT,
+Currency::XAG,
+Currency::AFA,
+Currency::FIM,
+Currency::ALK,
+Currency::ADP,
+Currency::ESP,
+Currency::FRF,
+Currency::AOK,
+Currency::AON,
+Currency::AOR,
+Currency::ARA,
+Currency::ARP,
+Currency::ARY,
+Currency::RUR,
+Currency::ATS,
+Currency::AYM,
+Currency::AZM,
+Currency::BYB,
+Currency::BYR,
+Currency::BEC,
+Currency::BEF,
+Currency::BEL,
+Currency::BOP,
+Currency::BAD,
+Currency::BRB,
+Currency::BRC,
+Currency::BRE,
+Currency::BRN,
+Currency::BRR,
+Currency::BGJ,
+Currency::BGK,
+Currency::BGL,
+Currency::BGN,
+Currency::BUK,
+Currency::HRD,
+Currency::HRK,
+Currency::CUC,
+Currency::ANG,
+Currency::CYP,
+Currency::CSJ,
+Currency::CSK,
+Currency::ECS,
+Currency::ECV,
+Currency::GQE,
+Currency::EEK,
+Currency::XEU,
+Currency::GEK,
+Currency::DDM,
+Currency::DEM,
+Currency::GHC,
+Currency::GHP,
+Currency::GRD,
+Currency::GNE,
+Currency::GNS,
+Currency::GWE,
+Currency::GWP,
+Currency::ITL,
+Currency::ISJ,
+Currency::IEP,
+Currency::ILP,
+Currency::ILR,
+Currency::LAJ,
+Currency::LVL,
+Currency::LVR,
+Currency::LSM,
+Currency::ZAL,
+Currency::LTL,
+Currency::LTT,
+Currency::LUC,
+Currency::LUF,
+Currency::LUL,
+Currency::MGF,
+Currency::MVQ,
+Currency::MLF,
+Currency::MTL,
+Currency::MTP,
+Currency::MRO,
+Currency::MXP,
+Currency::MZE,
+Currency::MZM,
+Currency::NLG,
+Currency::NIC,
+Currency::PEH,
+Currency::PEI,
+Currency::PES,
+Currency::PLZ,
+Currency::PTE,
+Currency::ROK,
+Currency::ROL,
+Currency::STD,
+Currency::CSD,
+Currency::SLL,
+Currency::SKK,
+Currency::SIT,
+Currency::RHD,
+Currency::ESA,
+Currency::ESB,
+Currency::SDD,
+Currency::SDP,
+Currency::SRG,
+Currency::CHC,
+Currency::TJR,
+Currency::TPE,
+Currency::TRL,
+Currency::TMM,
+Currency::UGS,
+Currency::UGW,
+Currency::UAK,
+Currency::SUR,
+Currency::USS,
+Currency::UYN,
+Currency::UYP,
+Currency::VEB,
+Currency::VEF,
+Currency::VNC,
+Currency::YDD,
+Currency::YUD,
+Currency::YUM,
+Currency::YUN,
+Currency::ZRN,
+Currency::ZRZ,
+Currency::ZMK,
+Currency::ZWC,
+Currency::ZWD,
+Currency::ZWN,
+Currency::ZWR,
+Currency::ZWL,
+Currency::XFO,
+Currency::XRE,
+Currency::XFU,
+
+];
+
+
+impl Currency {
+    ///Returns the variant for an upper case alpha3 code, if exists
+    pub const fn from_alpha3(code: &[u8; 3]) -> Option<Currency> {
+        match code {
+
+            b"AFN" => Some(Currency::AFN),
+            b"EUR" => Some(Currency::EUR),
+            b"ALL" => Some(Currency::ALL),
+            b"DZD" => Some(Currency::DZD),
+            b"USD" => Some(Currency::USD),
+            b"AOA" => Some(Currency::AOA),
+            b"XCD" => Some(Currency::XCD),
+            b"XAD" => Some(Currency::XAD),
+            b"ARS" => Some(Currency::ARS),
+            b"AMD" => Some(Currency::AMD),
+            b"AWG" => Some(Currency::AWG),
+            b"AUD" => Some(Currency::AUD),
+            b"AZN" => Some(Currency::AZN),
+            b"BSD" => Some(Currency::BSD),
+            b"BHD" => Some(Currency::BHD),
+            b"BDT" => Some(Currency::BDT),
+            b"BBD" => Some(Currency::BBD),
+            b"BYN" => Some(Currency::BYN),
+            b"BZD" => Some(Currency::BZD),
+            b"XOF" => Some(Currency::XOF),
+            b"BMD" => Some(Currency::BMD),
+            b"INR" => Some(Currency::INR),
+            b"BTN" => Some(Currency::BTN),
+            b"BOB" => Some(Currency::BOB),
+            b"BOV" => Some(Currency::BOV),
+            b"BAM" => Some(Currency::BAM),
+            b"BWP" => Some(Currency::BWP),
+            b"NOK" => Some(Currency::NOK),
+            b"BRL" => Some(Currency::BRL),
+            b"BND" => Some(Currency::BND),
+            b"BIF" => Some(Currency::BIF),
+            b"CVE" => Some(Currency::CVE),
+            b"KHR" => Some(Currency::KHR),
+            b"XAF" => Some(Currency::XAF),
+            b"CAD" => Some(Currency::CAD),
+            b"KYD" => Some(Currency::KYD),
+            b"CLP" => Some(Currency::CLP),
+            b"CLF" => Some(Currency::CLF),
+            b"CNY" => Some(Currency::CNY),
+            b"COP" => Some(Currency::COP),
+            b"COU" => Some(Currency::COU),
+            b"KMF" => Some(Currency::KMF),
+            b"CDF" => Some(Currency::CDF),
+            b"NZD" => Some(Currency::NZD),
+            b"CRC" => Some(Currency::CRC),
+            b"CUP" => Some(Currency::CUP),
+            b"XCG" => Some(Currency::XCG),
+            b"CZK" => Some(Currency::CZK),
+            b"DKK" => Some(Currency::DKK),
+            b"DJF" => Some(Currency::DJF),
+            b"DOP" => Some(Currency::DOP),
+            b"EGP" => Some(Currency::EGP),
+            b"SVC" => Some(Currency::SVC),
+            b"ERN" => Some(Currency::ERN),
+            b"SZL" => Some(Currency::SZL),
+            b"ETB" => Some(Currency::ETB),
+            b"FKP" => Some(Currency::FKP),
+            b"FJD" => Some(Currency::FJD),
+            b"XPF" => Some(Currency::XPF),
+            b"GMD" => Some(Currency::GMD),
+            b"GEL" => Some(Currency::GEL),
+            b"GHS" => Some(Currency::GHS),
+            b"GIP" => Some(Currency::GIP),
+            b"GTQ" => Some(Currency::GTQ),
+            b"GBP" => Some(Currency::GBP),
+            b"GNF" => Some(Currency::GNF),
+            b"GYD" => Some(Currency::GYD),
+            b"HTG" => Some(Currency::HTG),
+            b"HNL" => Some(Currency::HNL),
+            b"HKD" => Some(Currency::HKD),
+            b"HUF" => Some(Currency::HUF),
+            b"ISK" => Some(Currency::ISK),
+            b"IDR" => Some(Currency::IDR),
+            b"XDR" => Some(Currency::XDR),
+            b"IRR" => Some(Currency::IRR),
+            b"IQD" => Some(Currency::IQD),
+            b"ILS" => Some(Currency::ILS),
+            b"JMD" => Some(Currency::JMD),
+            b"JPY" => Some(Currency::JPY),
+            b"JOD" => Some(Currency::JOD),
+            b"KZT" => Some(Currency::KZT),
+            b"KES" => Some(Currency::KES),
+            b"KPW" => Some(Currency::KPW),
+            b"KRW" => Some(Currency::KRW),
+            b"KWD" => Some(Currency::KWD),
+            b"KGS" => Some(Currency::KGS),
+            b"LAK" => Some(Currency::LAK),
+            b"LBP" => Some(Currency::LBP),
+            b"LSL" => Some(Currency::LSL),
+            b"ZAR" => Some(Currency::ZAR),
+            b"LRD" => Some(Currency::LRD),
+            b"LYD" => Some(Currency::LYD),
+            b"CHF" => Some(Currency::CHF),
+            b"MOP" => Some(Currency::MOP),
+            b"MGA" => Some(Currency::MGA),
+            b"MWK" => Some(Currency::MWK),
+            b"MYR" => Some(Currency::MYR),
+            b"MVR" => Some(Currency::MVR),
+            b"MRU" => Some(Currency::MRU),
+            b"MUR" => Some(Currency::MUR),
+            b"XUA" => Some(Currency::XUA),
+            b"MXN" => Some(Currency::MXN),
+            b"MXV" => Some(Currency::MXV),
+            b"MDL" => Some(Currency::MDL),
+            b"MNT" => Some(Currency::MNT),
+            b"MAD" => Some(Currency::MAD),
+            b"MZN" => Some(Currency::MZN),
+            b"MMK" => Some(Currency::MMK),
+            b"NAD" => Some(Currency::NAD),
+            b"NPR" => Some(Currency::NPR),
+            b"NIO" => Some(Currency::NIO),
+            b"NGN" => Some(Currency::NGN),
+            b"MKD" => Some(Currency::MKD),
+            b"OMR" => Some(Currency::OMR),
+            b"PKR" => Some(Currency::PKR),
+            b"PAB" => Some(Currency::PAB),
+            b"PGK" => Some(Currency::PGK),
+            b"PYG" => Some(Currency::PYG),
+            b"PEN" => Some(Currency::PEN),
+            b"PHP" => Some(Currency::PHP),
+            b"PLN" => Some(Currency::PLN),
+            b"QAR" => Some(Currency::QAR),
+            b"RON" => Some(Currency::RON),
+            b"RUB" => Some(Currency::RUB),
+            b"RWF" => Some(Currency::RWF),
+            b"SHP" => Some(Currency::SHP),
+            b"WST" => Some(Currency::WST),
+            b"STN" => Some(Currency::STN),
+            b"SAR" => Some(Currency::SAR),
+            b"RSD" => Some(Currency::RSD),
+            b"SCR" => Some(Currency::SCR),
+            b"SLE" => Some(Currency::SLE),
+            b"SGD" => Some(Currency::SGD),
+            b"XSU" => Some(Currency::XSU),
+            b"SBD" => Some(Currency::SBD),
+            b"SOS" => Some(Currency::SOS),
+            b"SSP" => Some(Currency::SSP),
+            b"LKR" => Some(Currency::LKR),
+            b"SDG" => Some(Currency::SDG),
+            b"SRD" => Some(Currency::SRD),
+            b"SEK" => Some(Currency::SEK),
+            b"CHE" => Some(Currency::CHE),
+            b"CHW" => Some(Currency::CHW),
+            b"SYP" => Some(Currency::SYP),
+            b"TWD" => Some(Currency::TWD),
+            b"TJS" => Some(Currency::TJS),
+            b"TZS" => Some(Currency::TZS),
+            b"THB" => Some(Currency::THB),
+            b"TOP" => Some(Currency::TOP),
+            b"TTD" => Some(Currency::TTD),
+            b"TND" => Some(Currency::TND),
+            b"TRY" => Some(Currency::TRY),
+            b"TMT" => Some(Currency::TMT),
+            b"UGX" => Some(Currency::UGX),
+            b"UAH" => Some(Currency::UAH),
+            b"AED" => Some(Currency::AED),
+            b"USN" => Some(Currency::USN),
+            b"UYU" => Some(Currency::UYU),
+            b"UYI" => Some(Currency::UYI),
+            b"UYW" => Some(Currency::UYW),
+            b"UZS" => Some(Currency::UZS),
+            b"VUV" => Some(Currency::VUV),
+            b"VES" => Some(Currency::VES),
+            b"VED" => Some(Currency::VED),
+            b"VND" => Some(Currency::VND),
+            b"YER" => Some(Currency::YER),
+            b"ZMW" => Some(Currency::ZMW),
+            b"ZWG" => Some(Currency::ZWG),
+            b"XBA" => Some(Currency::XBA),
+            b"XBB" => Some(Currency::XBB),
+            b"XBC" => Some(Currency::XBC),
+            b"XBD" => Some(Currency::XBD),
+            b"XTS" => Some(Currency::XTS),
+            b"XXX" => Some(Currency::XXX),
+            b"XAU" => Some(Currency::XAU),
+            b"XPD" => Some(Currency::XPD),
+            b"XPT" => Some(Currency::XPT),
+            b"XAG" => Some(Currency::XAG),
+            b"AFA" => Some(Currency::AFA),
+            b"FIM" => Some(Currency::FIM),
+            b"ALK" => Some(Currency::ALK),
+            b"ADP" => Some(Currency::ADP),
+            b"ESP" => Some(Currency::ESP),
+            b"FRF" => Some(Currency::FRF),
+            b"AOK" => Some(Currency::AOK),
+            b"AON" => Some(Currency::AON),
+            b"AOR" => Some(Currency::AOR),
+            b"ARA" => Some(Currency::ARA),
+            b"ARP" => Some(Currency::ARP),
+            b"ARY" => Some(Currency::ARY),
+            b"RUR" => Some(Currency::RUR),
+            b"ATS" => Some(Currency::ATS),
+            b"AYM" => Some(Currency::AYM),
+            b"AZM" => Some(Currency::AZM),
+            b"BYB" => Some(Currency::BYB),
+            b"BYR" => Some(Currency::BYR),
+            b"BEC" => Some(Currency::BEC),
+            b"BEF" => Some(Currency::BEF),
+            b"BEL" => Some(Currency::BEL),
+            b"BOP" => Some(Currency::BOP),
+            b"BAD" => Some(Currency::BAD),
+            b"BRB" => Some(Currency::BRB),
+            b"BRC" => Some(Currency::BRC),
+            b"BRE" => Some(Currency::BRE),
+            b"BRN" => Some(Currency::BRN),
+            b"BRR" => Some(Currency::BRR),
+            b"BGJ" => Some(Currency::BGJ),
+            b"BGK" => Some(Currency::BGK),
+            b"BGL" => Some(Currency::BGL),
+            b"BGN" => Some(Currency::BGN),
+            b"BUK" => Some(Currency::BUK),
+            b"HRD" => Some(Currency::HRD),
+            b"HRK" => Some(Currency::HRK),
+            b"CUC" => Some(Currency::CUC),
+            b"ANG" => Some(Currency::ANG),
+            b"CYP" => Some(Currency::CYP),
+            b"CSJ" => Some(Currency::CSJ),
+            b"CSK" => Some(Currency::CSK),
+            b"ECS" => Some(Currency::ECS),
+            b"ECV" => Some(Currency::ECV),
+            b"GQE" => Some(Currency::GQE),
+            b"EEK" => Some(Currency::EEK),
+            b"XEU" => Some(Currency::XEU),
+            b"GEK" => Some(Currency::GEK),
+            b"DDM" => Some(Currency::DDM),
+            b"DEM" => Some(Currency::DEM),
+            b"GHC" => Some(Currency::GHC),
+            b"GHP" => Some(Currency::GHP),
+            b"GRD" => Some(Currency::GRD),
+            b"GNE" => Some(Currency::GNE),
+            b"GNS" => Some(Currency::GNS),
+            b"GWE" => Some(Currency::GWE),
+            b"GWP" => Some(Currency::GWP),
+            b"ITL" => Some(Currency::ITL),
+            b"ISJ" => Some(Currency::ISJ),
+            b"IEP" => Some(Currency::IEP),
+            b"ILP" => Some(Currency::ILP),
+            b"ILR" => Some(Currency::ILR),
+            b"LAJ" => Some(Currency::LAJ),
+            b"LVL" => Some(Currency::LVL),
+            b"LVR" => Some(Currency::LVR),
+            b"LSM" => Some(Currency::LSM),
+            b"ZAL" => Some(Currency::ZAL),
+            b"LTL" => Some(Currency::LTL),
+            b"LTT" => Some(Currency::LTT),
+            b"LUC" => Some(Currency::LUC),
+            b"LUF" => Some(Currency::LUF),
+            b"LUL" => Some(Currency::LUL),
+            b"MGF" => Some(Currency::MGF),
+            b"MVQ" => Some(Currency::MVQ),
+            b"MLF" => Some(Currency::MLF),
+            b"MTL" => Some(Currency::MTL),
+            b"MTP" => Some(Currency::MTP),
+            b"MRO" => Some(Currency::MRO),
+            b"MXP" => Some(Currency::MXP),
+            b"MZE" => Some(Currency::MZE),
+            b"MZM" => Some(Currency::MZM),
+            b"NLG" => Some(Currency::NLG),
+            b"NIC" => Some(Currency::NIC),
+            b"PEH" => Some(Currency::PEH),
+            b"PEI" => Some(Currency::PEI),
+            b"PES" => Some(Currency::PES),
+            b"PLZ" => Some(Currency::PLZ),
+            b"PTE" => Some(Currency::PTE),
+            b"ROK" => Some(Currency::ROK),
+            b"ROL" => Some(Currency::ROL),
+            b"STD" => Some(Currency::STD),
+            b"CSD" => Some(Currency::CSD),
+            b"SLL" => Some(Currency::SLL),
+            b"SKK" => Some(Currency::SKK),
+            b"SIT" => Some(Currency::SIT),
+            b"RHD" => Some(Currency::RHD),
+            b"ESA" => Some(Currency::ESA),
+            b"ESB" => Some(Currency::ESB),
+            b"SDD" => Some(Currency::SDD),
+            b"SDP" => Some(Currency::SDP),
+            b"SRG" => Some(Currency::SRG),
+            b"CHC" => Some(Currency::CHC),
+            b"TJR" => Some(Currency::TJR),
+            b"TPE" => Some(Currency::TPE),
+            b"TRL" => Some(Currency::TRL),
+            b"TMM" => Some(Currency::TMM),
+            b"UGS" => Some(Currency::UGS),
+            b"UGW" => Some(Currency::UGW),
+            b"UAK" => Some(Currency::UAK),
+            b"SUR" => Some(Currency::SUR),
+            b"USS" => Some(Currency::USS),
+            b"UYN" => Some(Currency::UYN),
+            b"UYP" => Some(Currency::UYP),
+            b"VEB" => Some(Currency::VEB),
+            b"VEF" => Some(Currency::VEF),
+            b"VNC" => Some(Currency::VNC),
+            b"YDD" => Some(Currency::YDD),
+            b"YUD" => Some(Currency::YUD),
+            b"YUM" => Some(Currency::YUM),
+            b"YUN" => Some(Currency::YUN),
+            b"ZRN" => Some(Currency::ZRN),
+            b"ZRZ" => Some(Currency::ZRZ),
+            b"ZMK" => Some(Currency::ZMK),
+            b"ZWC" => Some(Currency::ZWC),
+            b"ZWD" => Some(Currency::ZWD),
+            b"ZWN" => Some(Currency::ZWN),
+            b"ZWR" => Some(Currency::ZWR),
+            b"ZWL" => Some(Currency::ZWL),
+            b"XFO" => Some(Currency::XFO),
+            b"XRE" => Some(Currency::XRE),
+            b"XFU" => Some(Currency::XFU),
+            _ => None,
+        }
+    }
+}
+
+impl Currency {
+    ///Returns the variant for a numeric code, if exists
+    pub const fn from_numeric(numeric: u16) -> Option<Currency> {
+        match numeric {
+
+            971 => Some(Currency::AFN),
+            978 => Some(Currency::EUR),
+            8 => Some(Currency::ALL),
+            12 => Some(Currency::DZD),
+            840 => Some(Currency::USD),
+            973 => Some(Currency::AOA),
+            951 => Some(Currency::XCD),
+            396 => Some(Currency::XAD),
+            32 => Some(Currency::ARS),
+            51 => Some(Currency::AMD),
+            533 => Some(Currency::AWG),
+            36 => Some(Currency::AUD),
+            944 => Some(Currency::AZN),
+            44 => Some(Currency::BSD),
+            48 => Some(Currency::BHD),
+            50 => Some(Currency::BDT),
+            52 => Some(Currency::BBD),
+            933 => Some(Currency::BYN),
+            84 => Some(Currency::BZD),
+            952 => Some(Currency::XOF),
+            60 => Some(Currency::BMD),
+            356 => Some(Currency::INR),
+            64 => Some(Currency::BTN),
+            68 => Some(Currency::BOB),
+            984 => Some(Currency::BOV),
+            977 => Some(Currency::BAM),
+            72 => Some(Currency::BWP),
+            578 => Some(Currency::NOK),
+            986 => Some(Currency::BRL),
+            96 => Some(Currency::BND),
+            108 => Some(Currency::BIF),
+            132 => Some(Currency::CVE),
+            116 => Some(Currency::KHR),
+            950 => Some(Currency::XAF),
+            124 => Some(Currency::CAD),
+            136 => Some(Currency::KYD),
+            152 => Some(Currency::CLP),
+            990 => Some(Currency::CLF),
+            156 => Some(Currency::CNY),
+            170 => Some(Currency::COP),
+            970 => Some(Currency::COU),
+            174 => Some(Currency::KMF),
+            976 => Some(Currency::CDF),
+            554 => Some(Currency::NZD),
+            188 => Some(Currency::CRC),
+            192 => Some(Currency::CUP),
+            532 => Some(Currency::XCG),
+            203 => Some(Currency::CZK),
+            208 => Some(Currency::DKK),
+            262 => Some(Currency::DJF),
+            214 => Some(Currency::DOP),
+            818 => Some(Currency::EGP),
+            222 => Some(Currency::SVC),
+            232 => Some(Currency::ERN),
+            748 => Some(Currency::SZL),
+            230 => Some(Currency::ETB),
+            238 => Some(Currency::FKP),
+            242 => Some(Currency::FJD),
+            953 => Some(Currency::XPF),
+            270 => Some(Currency::GMD),
+            981 => Some(Currency::GEL),
+            936 => Some(Currency::GHS),
+            292 => Some(Currency::GIP),
+            320 => Some(Currency::GTQ),
+            826 => Some(Currency::GBP),
+            324 => Some(Currency::GNF),
+            328 => Some(Currency::GYD),
+            332 => Some(Currency::HTG),
+            340 => Some(Currency::HNL),
+            344 => Some(Currency::HKD),
+            348 => Some(Currency::HUF),
+            352 => Some(Currency::ISK),
+            360 => Some(Currency::IDR),
+            960 => Some(Currency::XDR),
+            364 => Some(Currency::IRR),
+            368 => Some(Currency::IQD),
+            376 => Some(Currency::ILS),
+            388 => Some(Currency::JMD),
+            392 => Some(Currency::JPY),
+            400 => Some(Currency::JOD),
+            398 => Some(Currency::KZT),
+            404 => Some(Currency::KES),
+            408 => Some(Currency::KPW),
+            410 => Some(Currency::KRW),
+            414 => Some(Currency::KWD),
+            417 => Some(Currency::KGS),
+            418 => Some(Currency::LAK),
+            422 => Some(Currency::LBP),
+            426 => Some(Currency::LSL),
+            710 => Some(Currency::ZAR),
+            430 => Some(Currency::LRD),
+            434 => Some(Currency::LYD),
+            756 => Some(Currency::CHF),
+            446 => Some(Currency::MOP),
+            969 => Some(Currency::MGA),
+            454 => Some(Currency::MWK),
+            458 => Some(Currency::MYR),
+            462 => Some(Currency::MVR),
+            929 => Some(Currency::MRU),
+            480 => Some(Currency::MUR),
+            965 => Some(Currency::XUA),
+            484 => Some(Currency::MXN),
+            979 => Some(Currency::MXV),
+            498 => Some(Currency::MDL),
+            496 => Some(Currency::MNT),
+            504 => Some(Currency::MAD),
+            943 => Some(Currency::MZN),
+            104 => Some(Currency::MMK),
+            516 => Some(Currency::NAD),
+            524 => Some(Currency::NPR),
+            558 => Some(Currency::NIO),
+            566 => Some(Currency::NGN),
+            807 => Some(Currency::MKD),
+            512 => Some(Currency::OMR),
+            586 => Some(Currency::PKR),
+            590 => Some(Currency::PAB),
+            598 => Some(Currency::PGK),
+            600 => Some(Currency::PYG),
+            604 => Some(Currency::PEN),
+            608 => Some(Currency::PHP),
+            985 => Some(Currency::PLN),
+            634 => Some(Currency::QAR),
+            946 => Some(Currency::RON),
+            643 => Some(Currency::RUB),
+            646 => Some(Currency::RWF),
+            654 => Some(Currency::SHP),
+            882 => Some(Currency::WST),
+            930 => Some(Currency::STN),
+            682 => Some(Currency::SAR),
+            941 => Some(Currency::RSD),
+            690 => Some(Currency::SCR),
+            925 => Some(Currency::SLE),
+            702 => Some(Currency::SGD),
+            994 => Some(Currency::XSU),
+            90 => Some(Currency::SBD),
+            706 => Some(Currency::SOS),
+            728 => Some(Currency::SSP),
+            144 => Some(Currency::LKR),
+            938 => Some(Currency::SDG),
+            968 => Some(Currency::SRD),
+            752 => Some(Currency::SEK),
+            947 => Some(Currency::CHE),
+            948 => Some(Currency::CHW),
+            760 => Some(Currency::SYP),
+            901 => Some(Currency::TWD),
+            972 => Some(Currency::TJS),
+            834 => Some(Currency::TZS),
+            764 => Some(Currency::THB),
+            776 => Some(Currency::TOP),
+            780 => Some(Currency::TTD),
+            788 => Some(Currency::TND),
+            949 => Some(Currency::TRY),
+            934 => Some(Currency::TMT),
+            800 => Some(Currency::UGX),
+            980 => Some(Currency::UAH),
+            784 => Some(Currency::AED),
+            997 => Some(Currency::USN),
+            858 => Some(Currency::UYU),
+            940 => Some(Currency::UYI),
+            927 => Some(Currency::UYW),
+            860 => Some(Currency::UZS),
+            548 => Some(Currency::VUV),
+            928 => Some(Currency::VES),
+            926 => Some(Currency::VED),
+            704 => Some(Currency::VND),
+            886 => Some(Currency::YER),
+            967 => Some(Currency::ZMW),
+            924 => Some(Currency::ZWG),
+            955 => Some(Currency::XBA),
+            956 => Some(Currency::XBB),
+            957 => Some(Currency::XBC),
+            958 => Some(Currency::XBD),
+            963 => Some(Currency::XTS),
+            999 => Some(Currency::XXX),
+            959 => Some(Currency::XAU),
+            964 => Some(Currency::XPD),
+            962 => Some(Currency::XPT),
+            961 => Some(Currency::XAG),
+            4 => Some(Currency::AFA),
+            246 => Some(Currency::FIM),
+            20 => Some(Currency::ADP),
+            724 => Some(Currency::ESP),
+            250 => Some(Currency::FRF),
+            24 => Some(Currency::AOK),
+            982 => Some(Currency::AOR),
+            810 => Some(Currency::RUR),
+            40 => Some(Currency::ATS),
+            945 => Some(Currency::AYM),
+            31 => Some(Currency::AZM),
+            112 => Some(Currency::BYB),
+            974 => Some(Currency::BYR),
+            993 => Some(Currency::BEC),
+            56 => Some(Currency::BEF),
+            992 => Some(Currency::BEL),
+            70 => Some(Currency::BAD),
+            76 => Some(Currency::BRB),
+            987 => Some(Currency::BRR),
+            100 => Some(Currency::BGJ),
+            975 => Some(Currency::BGN),
+            191 => Some(Currency::HRD),
+            931 => Some(Currency::CUC),
+            196 => Some(Currency::CYP),
+            200 => Some(Currency::CSK),
+            218 => Some(Currency::ECS),
+            983 => Some(Currency::ECV),
+            226 => Some(Currency::GQE),
+            233 => Some(Currency::EEK),
+            954 => Some(Currency::XEU),
+            268 => Some(Currency::GEK),
+            278 => Some(Currency::DDM),
+            276 => Some(Currency::DEM),
+            288 => Some(Currency::GHC),
+            939 => Some(Currency::GHP),
+            300 => Some(Currency::GRD),
+            624 => Some(Currency::GWE),
+            380 => Some(Currency::ITL),
+            372 => Some(Currency::IEP),
+            428 => Some(Currency::LVL),
+            991 => Some(Currency::ZAL),
+            440 => Some(Currency::LTL),
+            989 => Some(Currency::LUC),
+            442 => Some(Currency::LUF),
+            988 => Some(Currency::LUL),
+            450 => Some(Currency::MGF),
+            466 => Some(Currency::MLF),
+            470 => Some(Currency::MTL),
+            478 => Some(Currency::MRO),
+            508 => Some(Currency::MZE),
+            528 => Some(Currency::NLG),
+            616 => Some(Currency::PLZ),
+            620 => Some(Currency::PTE),
+            642 => Some(Currency::ROK),
+            678 => Some(Currency::STD),
+            891 => Some(Currency::CSD),
+            694 => Some(Currency::SLL),
+            703 => Some(Currency::SKK),
+            705 => Some(Currency::SIT),
+            716 => Some(Currency::RHD),
+            996 => Some(Currency::ESA),
+            995 => Some(Currency::ESB),
+            736 => Some(Currency::SDD),
+            740 => Some(Currency::SRG),
+            762 => Some(Currency::TJR),
+            626 => Some(Currency::TPE),
+            792 => Some(Currency::TRL),
+            795 => Some(Currency::TMM),
+            804 => Some(Currency::UAK),
+            998 => Some(Currency::USS),
+            862 => Some(Currency::VEB),
+            937 => Some(Currency::VEF),
+            720 => Some(Currency::YDD),
+            890 => Some(Currency::YUD),
+            180 => Some(Currency::ZRN),
+            894 => Some(Currency::ZMK),
+            942 => Some(Currency::ZWN),
+            935 => Some(Currency::ZWR),
+            932 => Some(Currency::ZWL),
+            _ => None,
+        }
+    }
+}
+
+
+///Withdrawals listed in ISO 4217 list three, by currency in `ALL_CODES` order
+pub const WITHDRAWALS: & [Withdrawal] = &[
+
+    Withdrawal::new(Currency::EUR, "SERBIA AND MONTENEGRO", (2006, 10), (2006, 10)),
+    Withdrawal::new(Currency::SZL, "SWAZILAND", (2018, 8), (2018, 8)),
+    Withdrawal::new(Currency::IDR, "TIMOR-LESTE", (2002, 7), (2002, 7)),
+    Withdrawal::new(Currency::MWK, "MALAWI", (2016, 2), (2016, 2)),
+    Withdrawal::new(Currency::PEN, "PERU", (2015, 12), (2015, 12)),
+    Withdrawal::new(Currency::RON, "ROMANIA", (2015, 6), (2015, 6)),
+    Withdrawal::new(Currency::SDG, "SOUTH SUDAN", (2012, 9), (2012, 9)),
+    Withdrawal::new(Currency::TRY, "TURKEY", (2009, 1), (2009, 1)),
+    Withdrawal::new(Currency::AFA, "AFGHANISTAN", (2003, 1), (2003, 1)),
+    Withdrawal::new(Currency::FIM, "ÅLAND ISLANDS", (2002, 3), (2002, 3)),
+    Withdrawal::new(Currency::FIM, "FINLAND", (2002, 3), (2002, 3)),
+    Withdrawal::new(Currency::ALK, "ALBANIA", (1989, 12), (1989, 12)),
+    Withdrawal::new(Currency::ADP, "ANDORRA", (2003, 7), (2003, 7)),
+    Withdrawal::new(Currency::ESP, "ANDORRA", (2002, 3), (2002, 3)),
+    Withdrawal::new(Currency::ESP, "SPAIN", (2002, 3), (2002, 3)),
+    Withdrawal::new(Currency::FRF, "SAINT MARTIN", (1999, 1), (1999, 1)),
+    Withdrawal::new(Currency::FRF, "SAINT-BARTHÉLEMY", (1999, 1), (1999, 1)),
+    Withdrawal::new(Currency::FRF, "ANDORRA", (2002, 3), (2002, 3)),
+    Withdrawal::new(Currency::FRF, "FRANCE", (2002, 3), (2002, 3)),
+    Withdrawal::new(Currency::FRF, "FRENCH GUIANA", (2002, 3), (2002, 3)),
+    Withdrawal::new(Currency::FRF, "FRENCH SOUTHERN TERRITORIES", (2002, 3), (2002, 3)),
+    Withdrawal::new(Currency::FRF, "GUADELOUPE", (2002, 3), (2002, 3)),
+    Withdrawal::new(Currency::FRF, "MARTINIQUE", (2002, 3), (2002, 3)),
+    Withdrawal::new(Currency::FRF, "MAYOTTE", (2002, 3), (2002, 3)),
+    Withdrawal::new(Currency::FRF, "MONACO", (2002, 3), (2002, 3)),
+    Withdrawal::new(Currency::FRF, "RÉUNION", (2002, 3), (2002, 3)),
+    Withdrawal::new(Currency::FRF, "SAINT PIERRE AND MIQUELON", (2002, 3), (2002, 3)),
+    Withdrawal::new(Currency::AOK, "ANGOLA", (1991, 3), (1991, 3)),
+    Withdrawal::new(Currency::AON, "ANGOLA", (2000, 2), (2000, 2)),
+    Withdrawal::new(Currency::AOR, "ANGOLA", (2000, 2), (2000, 2)),
+    Withdrawal::new(Currency::ARA, "ARGENTINA", (1992, 1), (1992, 1)),
+    Withdrawal::new(Currency::ARP, "ARGENTINA", (1985, 7), (1985, 7)),
+    Withdrawal::new(Currency::ARY, "ARGENTINA", (1989, 1), (1990, 12)),
+    Withdrawal::new(Currency::RUR, "KYRGYZSTAN", (1993, 1), (1993, 1)),
+    Withdrawal::new(Currency::RUR, "TURKMENISTAN", (1993, 10), (1993, 10)),
+    Withdrawal::new(Currency::RUR, "MOLDOVA, REPUBLIC OF", (1993, 12), (1993, 12)),
+    Withdrawal::new(Currency::RUR, "GEORGIA", (1994, 4), (1994, 4)),
+    Withdrawal::new(Currency::RUR, "KAZAKHSTAN", (1994, 5), (1994, 5)),
+    Withdrawal::new(Currency::RUR, "BELARUS", (1994, 6), (1994, 6)),
+    Withdrawal::new(Currency::RUR, "UZBEKISTAN", (1994, 7), (1994, 7)),
+    Withdrawal::new(Currency::RUR, "ARMENIA", (1994, 8), (1994, 8)),
+    Withdrawal::new(Currency::RUR, "AZERBAIJAN", (1994, 8), (1994, 8)),
+    Withdrawal::new(Currency::RUR, "TAJIKISTAN", (1995, 5), (1995, 5)),
+    Withdrawal::new(Currency::RUR, "RUSSIAN FEDERATION", (2004, 1), (2004, 1)),
+    Withdrawal::new(Currency::ATS, "AUSTRIA", (2002, 3), (2002, 3)),
+    Withdrawal::new(Currency::AYM, "AZERBAIJAN", (2005, 10), (2005, 10)),
+    Withdrawal::new(Currency::AZM, "AZERBAIJAN", (2005, 12), (2005, 12)),
+    Withdrawal::new(Currency::BYB, "BELARUS", (2001, 1), (2001, 1)),
+    Withdrawal::new(Currency::BYR, "BELARUS", (2017, 1), (2017, 1)),
+    Withdrawal::new(Currency::BEC, "BELGIUM", (1990, 3), (1990, 3)),
+    Withdrawal::new(Currency::BEF, "BELGIUM", (2002, 3), (2002, 3)),
+    Withdrawal::new(Currency::BEL, "BELGIUM", (1990, 3), (1990, 3)),
+    Withdrawal::new(Currency::BOP, "BOLIVIA", (1987, 2), (1987, 2)),
+    Withdrawal::new(Currency::BAD, "BOSNIA AND HERZEGOVINA", (1998, 7), (1998, 7)),
+    Withdrawal::new(Currency::BRB, "BRAZIL", (1986, 3), (1986, 3)),
+    Withdrawal::new(Currency::BRC, "BRAZIL", (1989, 2), (1989, 2)),
+    Withdrawal::new(Currency::BRE, "BRAZIL", (1993, 3), (1993, 3)),
+    Withdrawal::new(Currency::BRN, "BRAZIL", (1990, 3), (1990, 3)),
+    Withdrawal::new(Currency::BRR, "BRAZIL", (1994, 7), (1994, 7)),
+    Withdrawal::new(Currency::BGJ, "BULGARIA", (1989, 1), (1990, 12)),
+    Withdrawal::new(Currency::BGK, "BULGARIA", (1989, 1), (1990, 12)),
+    Withdrawal::new(Currency::BGL, "BULGARIA", (2003, 11), (2003, 11)),
+    Withdrawal::new(Currency::BGN, "BULGARIA", (2026, 1), (2026, 1)),
+    Withdrawal::new(Currency::BUK, "BURMA", (1990, 2), (1990, 2)),
+    Withdrawal::new(Currency::HRD, "CROATIA", (1995, 1), (1995, 1)),
+    Withdrawal::new(Currency::HRK, "CROATIA", (2015, 6), (2015, 6)),
+    Withdrawal::new(Currency::HRK, "CROATIA", (2023, 1), (2023, 1)),
+    Withdrawal::new(Currency::CUC, "CUBA", (2021, 6), (2021, 6)),
+    Withdrawal::new(Currency::ANG, "NETHERLANDS ANTILLES", (2010, 10), (2010, 10)),
+    Withdrawal::new(Currency::ANG, "CURAÇAO", (2025, 3), (2025, 3)),
+    Withdrawal::new(Currency::ANG, "SINT MAARTEN (DUTCH PART)", (2025, 3), (2025, 3)),
+    Withdrawal::new(Currency::CYP, "CYPRUS", (2008, 1), (2008, 1)),
+    Withdrawal::new(Currency::CSJ, "CZECHOSLOVAKIA", (1989, 1), (1990, 12)),
+    Withdrawal::new(Currency::CSK, "CZECHOSLOVAKIA", (1993, 3), (1993, 3)),
+    Withdrawal::new(Currency::ECS, "ECUADOR", (2000, 9), (2000, 9)),
+    Withdrawal::new(Currency::ECV, "ECUADOR", (2000, 9), (2000, 9)),
+    Withdrawal::new(Currency::GQE, "EQUATORIAL GUINEA", (1986, 6), (1986, 6)),
+    Withdrawal::new(Currency::EEK, "ESTONIA", (2011, 1), (2011, 1)),
+    Withdrawal::new(Currency::XEU, "EUROPEAN MONETARY CO-OPERATION FUND (EMCF)", (1999, 1), (1999, 1)),
+    Withdrawal::new(Currency::GEK, "GEORGIA", (1995, 10), (1995, 10)),
+    Withdrawal::new(Currency::DDM, "GERMAN DEMOCRATIC REPUBLIC", (1990, 7), (1990, 9)),
+    Withdrawal::new(Currency::DEM, "GERMANY", (2002, 3), (2002, 3)),
+    Withdrawal::new(Currency::GHC, "GHANA", (2008, 1), (2008, 1)),
+    Withdrawal::new(Currency::GHP, "GHANA", (2007, 6), (2007, 6)),
+    Withdrawal::new(Currency::GRD, "GREECE", (2002, 3), (2002, 3)),
+    Withdrawal::new(Currency::GNE, "GUINEA", (1989, 12), (1989, 12)),
+    Withdrawal::new(Currency::GNS, "GUINEA", (1986, 2), (1986, 2)),
+    Withdrawal::new(Currency::GWE, "GUINEA-BISSAU", (1978, 1), (1981, 12)),
+    Withdrawal::new(Currency::GWP, "GUINEA-BISSAU", (1997, 5), (1997, 5)),
+    Withdrawal::new(Currency::ITL, "HOLY SEE (VATICAN CITY STATE)", (2002, 3), (2002, 3)),
+    Withdrawal::new(Currency::ITL, "ITALY", (2002, 3), (2002, 3)),
+    Withdrawal::new(Currency::ITL, "SAN MARINO", (2002, 3), (2002, 3)),
+    Withdrawal::new(Currency::ISJ, "ICELAND", (1989, 1), (1990, 12)),
+    Withdrawal::new(Currency::IEP, "IRELAND", (2002, 3), (2002, 3)),
+    Withdrawal::new(Currency::ILP, "ISRAEL", (1978, 1), (1981, 12)),
+    Withdrawal::new(Currency::ILR, "ISRAEL", (1989, 1), (1990, 12)),
+    Withdrawal::new(Currency::LAJ, "LAO", (1979, 12), (1979, 12)),
+    Withdrawal::new(Currency::LVL, "LATVIA", (2014, 1), (2014, 1)),
+    Withdrawal::new(Currency::LVR, "LATVIA", (1994, 12), (1994, 12)),
+    Withdrawal::new(Currency::LSM, "LESOTHO", (1985, 5), (1985, 5)),
+    Withdrawal::new(Currency::ZAL, "LESOTHO", (1995, 3), (1995, 3)),
+    Withdrawal::new(Currency::ZAL, "SOUTH AFRICA", (1995, 3), (1995, 3)),
+    Withdrawal::new(Currency::LTL, "LITHUANIA", (2014, 12), (2014, 12)),
+    Withdrawal::new(Currency::LTT, "LITHUANIA", (1993, 7), (1993, 7)),
+    Withdrawal::new(Currency::LUC, "LUXEMBOURG", (1990, 3), (1990, 3)),
+    Withdrawal::new(Currency::LUF, "LUXEMBOURG", (2002, 3), (2002, 3)),
+    Withdrawal::new(Currency::LUL, "LUXEMBOURG", (1990, 3), (1990, 3)),
+    Withdrawal::new(Currency::MGF, "MADAGASCAR", (2004, 12), (2004, 12)),
+    Withdrawal::new(Currency::MVQ, "MALDIVES", (1989, 12), (1989, 12)),
+    Withdrawal::new(Currency::MLF, "MALI", (1984, 11), (1984, 11)),
+    Withdrawal::new(Currency::MTL, "MALTA", (2008, 1), (2008, 1)),
+    Withdrawal::new(Currency::MTP, "MALTA", (1983, 6), (1983, 6)),
+    Withdrawal::new(Currency::MRO, "MAURITANIA", (2017, 12), (2017, 12)),
+    Withdrawal::new(Currency::MXP, "MEXICO", (1993, 1), (1993, 1)),
+    Withdrawal::new(Currency::MZE, "MOZAMBIQUE", (1978, 1), (1981, 12)),
+    Withdrawal::new(Currency::MZM, "MOZAMBIQUE", (2006, 6), (2006, 6)),
+    Withdrawal::new(Currency::NLG, "NETHERLANDS", (2002, 3), (2002, 3)),
+    Withdrawal::new(Currency::NIC, "NICARAGUA", (1990, 10), (1990, 10)),
+    Withdrawal::new(Currency::PEH, "PERU", (1989, 1), (1990, 12)),
+    Withdrawal::new(Currency::PEI, "PERU", (1991, 7), (1991, 7)),
+    Withdrawal::new(Currency::PES, "PERU", (1986, 2), (1986, 2)),
+    Withdrawal::new(Currency::PLZ, "POLAND", (1997, 1), (1997, 1)),
+    Withdrawal::new(Currency::PTE, "PORTUGAL", (2002, 3), (2002, 3)),
+    Withdrawal::new(Currency::ROK, "ROMANIA", (1989, 1), (1990, 12)),
+    Withdrawal::new(Currency::ROL, "ROMANIA", (2005, 6), (2005, 6)),
+    Withdrawal::new(Currency::STD, "SAO TOME AND PRINCIPE", (2017, 12), (2017, 12)),
+    Withdrawal::new(Currency::CSD, "SERBIA AND MONTENEGRO", (2006, 10), (2006, 10)),
+    Withdrawal::new(Currency::SLL, "SIERRA LEONE", (2023, 12), (2023, 12)),
+    Withdrawal::new(Currency::SKK, "SLOVAKIA", (2009, 1), (2009, 1)),
+    Withdrawal::new(Currency::SIT, "SLOVENIA", (2007, 1), (2007, 1)),
+    Withdrawal::new(Currency::RHD, "SOUTHERN RHODESIA", (1978, 1), (1981, 12)),
+    Withdrawal::new(Currency::ESA, "SPAIN", (1978, 1), (1981, 12)),
+    Withdrawal::new(Currency::ESB, "SPAIN", (1994, 12), (1994, 12)),
+    Withdrawal::new(Currency::SDD, "SUDAN", (2007, 7), (2007, 7)),
+    Withdrawal::new(Currency::SDP, "SUDAN", (1998, 6), (1998, 6)),
+    Withdrawal::new(Currency::SRG, "SURINAME", (2003, 12), (2003, 12)),
+    Withdrawal::new(Currency::CHC, "SWITZERLAND", (2004, 11), (2004, 11)),
+    Withdrawal::new(Currency::TJR, "TAJIKISTAN", (2001, 4), (2001, 4)),
+    Withdrawal::new(Currency::TPE, "TIMOR-LESTE", (2002, 11), (2002, 11)),
+    Withdrawal::new(Currency::TRL, "TURKEY", (2005, 12), (2005, 12)),
+    Withdrawal::new(Currency::TMM, "TURKMENISTAN", (2009, 1), (2009, 1)),
+    Withdrawal::new(Currency::UGS, "UGANDA", (1987, 5), (1987, 5)),
+    Withdrawal::new(Currency::UGW, "UGANDA", (1989, 1), (1990, 12)),
+    Withdrawal::new(Currency::UAK, "UKRAINE", (1996, 9), (1996, 9)),
+    Withdrawal::new(Currency::SUR, "UNION OF SOVIET SOCIALIST REPUBLICS", (1990, 12), (1990, 12)),
+    Withdrawal::new(Currency::USS, "UNITED STATES", (2014, 3), (2014, 3)),
+    Withdrawal::new(Currency::UYN, "URUGUAY", (1989, 12), (1989, 12)),
+    Withdrawal::new(Currency::UYP, "URUGUAY", (1993, 3), (1993, 3)),
+    Withdrawal::new(Currency::VEB, "VENEZUELA", (2008, 1), (2008, 1)),
+    Withdrawal::new(Currency::VEF, "VENEZUELA", (2011, 12), (2011, 12)),
+    Withdrawal::new(Currency::VEF, "VENEZUELA (BOLIVARIAN REPUBLIC OF)", (2016, 2), (2016, 2)),
+    Withdrawal::new(Currency::VEF, "VENEZUELA (BOLIVARIAN REPUBLIC OF)", (2018, 8), (2018, 8)),
+    Withdrawal::new(Currency::VNC, "VIETNAM", (1989, 1), (1990, 12)),
+    Withdrawal::new(Currency::YDD, "YEMEN, DEMOCRATIC", (1991, 9), (1991, 9)),
+    Withdrawal::new(Currency::YUD, "YUGOSLAVIA", (1990, 1), (1990, 1)),
+    Withdrawal::new(Currency::YUM, "YUGOSLAVIA", (2003, 7), (2003, 7)),
+    Withdrawal::new(Currency::YUN, "YUGOSLAVIA", (1995, 11), (1995, 11)),
+    Withdrawal::new(Currency::ZRN, "ZAIRE", (1999, 6), (1999, 6)),
+    Withdrawal::new(Currency::ZRZ, "ZAIRE", (1994, 2), (1994, 2)),
+    Withdrawal::new(Currency::ZMK, "ZAMBIA", (2012, 12), (2012, 12)),
+    Withdrawal::new(Currency::ZWC, "ZIMBABWE", (1989, 12), (1989, 12)),
+    Withdrawal::new(Currency::ZWD, "ZIMBABWE", (2006, 8), (2006, 8)),
+    Withdrawal::new(Currency::ZWD, "ZIMBABWE", (2008, 8), (2008, 8)),
+    Withdrawal::new(Currency::ZWN, "ZIMBABWE", (2006, 9), (2006, 9)),
+    Withdrawal::new(Currency::ZWR, "ZIMBABWE", (2009, 6), (2009, 6)),
+    Withdrawal::new(Currency::ZWL, "ZIMBABWE", (2024, 9), (2024, 9)),
+    Withdrawal::new(Currency::XFO, "ZZ01_Gold-Franc", (2006, 10), (2006, 10)),
+    Withdrawal::new(Currency::XRE, "ZZ02_RINET Funds Code", (1999, 11), (1999, 11)),
+    Withdrawal::new(Currency::XFU, "ZZ05_UIC-Franc", (2013, 11), (2013, 11)),
+
+];
+
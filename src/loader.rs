@@ -0,0 +1,385 @@
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use crate::{Currency, Date, Decimal, LoadError};
+
+/// Layout of an offline rate file.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum RateFormat {
+    /// ECB `eurofxref-daily.xml` or `eurofxref-hist.xml`; every rate is
+    /// `EUR/<currency>`.
+    EcbXml,
+    /// ECB `eurofxref.csv` or `eurofxref-hist.csv` (the unzipped files): a
+    /// `Date, USD, JPY, ...` header, then one row per day. `N/A` cells are
+    /// skipped.
+    EcbCsv,
+    /// One `date,base,quote,rate` record per line, e.g.
+    /// `2025-10-17,USD,JPY,150.61`. Blank lines, `#` comments and a leading
+    /// `date,...` header are skipped.
+    Csv,
+}
+
+/// A code found while loading that deserves a look; the load still succeeds.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum LoadIssue {
+    /// Not an ISO 4217 code; its rates were skipped.
+    UnknownCode { line: usize, code: String },
+    /// A withdrawn ISO 4217 code (e.g. `CYP` in the ECB history); its rates
+    /// were loaded.
+    HistoricCode { line: usize, currency: Currency },
+}
+
+/// One parsed `base/quote` rate.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(crate) struct Record {
+    pub(crate) date: Date,
+    pub(crate) base: Currency,
+    pub(crate) quote: Currency,
+    pub(crate) rate: Decimal,
+}
+
+impl Record {
+    /// Rejects a same-currency rate other than 1, as `ExchangeRate::new` does.
+    fn new(
+        date: Date,
+        base: Currency,
+        quote: Currency,
+        rate: Decimal,
+        line: usize,
+    ) -> Result<Record, LoadError> {
+        if base == quote && rate.rescale(0) != Some(1) {
+            return Err(LoadError::InvalidRate { line });
+        }
+        Ok(Record {
+            date,
+            base,
+            quote,
+            rate,
+        })
+    }
+}
+
+/// Parses `text`, appending each code issue to `issues` unless already there.
+pub(crate) fn parse(
+    text: &str,
+    format: RateFormat,
+    known: &[LoadIssue],
+) -> Result<(Vec<Record>, Vec<LoadIssue>), LoadError> {
+    let mut codes = Codes {
+        known,
+        issues: Vec::new(),
+    };
+    let records = match format {
+        RateFormat::EcbXml => parse_ecb_xml(text, &mut codes),
+        RateFormat::EcbCsv => parse_ecb_csv(text, &mut codes),
+        RateFormat::Csv => parse_csv(text, &mut codes),
+    }?;
+    Ok((records, codes.issues))
+}
+
+struct Codes<'a> {
+    /// Issues reported by earlier loads, not repeated.
+    known: &'a [LoadIssue],
+    issues: Vec<LoadIssue>,
+}
+
+impl Codes<'_> {
+    /// Resolves `code`, flagging unknown and historic codes.
+    fn resolve(&mut self, code: &str, line: usize) -> Option<Currency> {
+        match code.parse::<Currency>() {
+            Ok(currency) => {
+                let known = self.known.iter().chain(&self.issues).any(
+                    |issue| matches!(issue, LoadIssue::HistoricCode { currency: c, .. } if *c == currency),
+                );
+                if currency.is_historic() && !known {
+                    self.issues.push(LoadIssue::HistoricCode { line, currency });
+                }
+                Some(currency)
+            }
+            Err(_) => {
+                let known = self.known.iter().chain(&self.issues).any(
+                    |issue| matches!(issue, LoadIssue::UnknownCode { code: c, .. } if c == code),
+                );
+                if !known {
+                    self.issues.push(LoadIssue::UnknownCode {
+                        line,
+                        code: code.to_string(),
+                    });
+                }
+                None
+            }
+        }
+    }
+}
+
+fn parse_ecb_xml(text: &str, codes: &mut Codes) -> Result<Vec<Record>, LoadError> {
+    let mut records = Vec::new();
+    let mut date = None;
+    let mut line = 1;
+    let mut rest = text;
+    while let Some(start) = rest.find("<Cube") {
+        line += newlines(&rest[..start]);
+        let tail = &rest[start..];
+        let end = tail.find('>').ok_or(LoadError::Malformed { line })?;
+        let tag = &tail[..end];
+        let mut currency = None;
+        let mut rate = None;
+        for (name, value) in tag.split_whitespace().skip(1).filter_map(attribute) {
+            match name {
+                "time" => date = Some(value.parse().map_err(|_| LoadError::InvalidDate { line })?),
+                "currency" => currency = Some(value),
+                "rate" => rate = Some(value),
+                _ => {}
+            }
+        }
+        match (currency, rate) {
+            (Some(currency), Some(rate)) => {
+                let date = date.ok_or(LoadError::Malformed { line })?;
+                let rate = parse_rate(rate, line)?;
+                if let Some(quote) = codes.resolve(currency, line) {
+                    records.push(Record::new(date, Currency::EUR, quote, rate, line)?);
+                }
+            }
+            (None, None) => {}
+            _ => return Err(LoadError::Malformed { line }),
+        }
+        line += newlines(tag);
+        rest = &tail[end..];
+    }
+    Ok(records)
+}
+
+/// Splits `name='value'` (or `"value"`), dropping a self-closing `/`.
+fn attribute(token: &str) -> Option<(&str, &str)> {
+    let (name, value) = token.trim_end_matches('/').split_once('=')?;
+    let value = value
+        .strip_prefix('\'')
+        .and_then(|v| v.strip_suffix('\''))
+        .or_else(|| value.strip_prefix('"').and_then(|v| v.strip_suffix('"')))?;
+    Some((name, value))
+}
+
+fn newlines(text: &str) -> usize {
+    text.bytes().filter(|b| *b == b'\n').count()
+}
+
+fn parse_ecb_csv(text: &str, codes: &mut Codes) -> Result<Vec<Record>, LoadError> {
+    let mut lines = numbered_lines(text);
+    let (header_line, header) = lines.next().ok_or(LoadError::Malformed { line: 1 })?;
+    let mut fields = header.split(',').map(str::trim);
+    if !fields
+        .next()
+        .is_some_and(|field| field.eq_ignore_ascii_case("date"))
+    {
+        return Err(LoadError::Malformed { line: header_line });
+    }
+    let columns: Vec<Option<Currency>> = fields
+        .map(|code| match code {
+            // Trailing comma.
+            "" => None,
+            code => codes.resolve(code, header_line),
+        })
+        .collect();
+    let mut records = Vec::new();
+    for (line, row) in lines {
+        let mut fields = row.split(',').map(str::trim);
+        let date = fields.next().unwrap_or_default();
+        let date = parse_ecb_date(date).ok_or(LoadError::InvalidDate { line })?;
+        for (i, value) in fields.enumerate() {
+            let quote = match columns.get(i) {
+                Some(quote) => *quote,
+                None if value.is_empty() => None,
+                None => return Err(LoadError::Malformed { line }),
+            };
+            if value.is_empty() || value == "N/A" {
+                continue;
+            }
+            let rate = parse_rate(value, line)?;
+            if let Some(quote) = quote {
+                records.push(Record::new(date, Currency::EUR, quote, rate, line)?);
+            }
+        }
+    }
+    Ok(records)
+}
+
+/// `2025-10-17` (history files) or `17 October 2025` (daily file).
+fn parse_ecb_date(s: &str) -> Option<Date> {
+    if let Ok(date) = s.parse() {
+        return Some(date);
+    }
+    const MONTHS: [&str; 12] = [
+        "January",
+        "February",
+        "March",
+        "April",
+        "May",
+        "June",
+        "July",
+        "August",
+        "September",
+        "October",
+        "November",
+        "December",
+    ];
+    let mut parts = s.split_whitespace();
+    let day = parts.next()?.parse().ok()?;
+    let month = parts.next()?;
+    let month = MONTHS.iter().position(|m| m.eq_ignore_ascii_case(month))? + 1;
+    let year = parts.next()?.parse().ok()?;
+    if parts.next().is_some() {
+        return None;
+    }
+    Date::new(year, month as u8, day)
+}
+
+fn parse_csv(text: &str, codes: &mut Codes) -> Result<Vec<Record>, LoadError> {
+    let mut records = Vec::new();
+    for (i, (line, row)) in numbered_lines(text).enumerate() {
+        let fields: Vec<&str> = row.split(',').map(str::trim).collect();
+        let [date, base, quote, rate] = fields[..] else {
+            return Err(LoadError::Malformed { line });
+        };
+        if i == 0 && date.eq_ignore_ascii_case("date") {
+            continue;
+        }
+        let date = date.parse().map_err(|_| LoadError::InvalidDate { line })?;
+        let rate = parse_rate(rate, line)?;
+        let base = codes.resolve(base, line);
+        let quote = codes.resolve(quote, line);
+        if let (Some(base), Some(quote)) = (base, quote) {
+            records.push(Record::new(date, base, quote, rate, line)?);
+        }
+    }
+    Ok(records)
+}
+
+/// Non-blank, non-comment lines with their 1-based numbers.
+fn numbered_lines(text: &str) -> impl Iterator<Item = (usize, &str)> {
+    text.lines()
+        .enumerate()
+        .map(|(i, line)| (i + 1, line.trim()))
+        .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'))
+}
+
+fn parse_rate(s: &str, line: usize) -> Result<Decimal, LoadError> {
+    match s.parse::<Decimal>() {
+        Ok(rate) if rate.mantissa() > 0 => Ok(rate),
+        _ => Err(LoadError::InvalidRate { line }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse, LoadIssue, RateFormat, Record};
+    use crate::{Currency, Date, Decimal, LoadError};
+
+    const XML: &str = "<?xml version=\"1.0\" encoding=\"UTF-8\"?>
+<gesmes:Envelope xmlns:gesmes=\"http://www.gesmes.org/xml/2002-08-01\" xmlns=\"http://www.ecb.int/vocabulary/2002-08-01/eurofxref\">
+\t<gesmes:subject>Reference rates</gesmes:subject>
+\t<Cube>
+\t\t<Cube time='2025-10-17'>
+\t\t\t<Cube currency='USD' rate='1.1681'/>
+\t\t\t<Cube currency='JPY' rate='175.53'/>
+\t\t</Cube>
+\t\t<Cube time=\"2007-12-31\">
+\t\t\t<Cube currency=\"CYP\" rate=\"0.585274\" />
+\t\t\t<Cube currency=\"ABC\" rate=\"1\" />
+\t\t</Cube>
+\t</Cube>
+</gesmes:Envelope>";
+
+    fn record(date: &str, base: Currency, quote: Currency, rate: &str) -> Record {
+        Record {
+            date: date.parse().unwrap(),
+            base,
+            quote,
+            rate: rate.parse().unwrap(),
+        }
+    }
+
+    #[test]
+    fn test_ecb_xml() {
+        let (records, issues) = parse(XML, RateFormat::EcbXml, &[]).unwrap();
+        assert_eq!(
+            vec![
+                record("2025-10-17", Currency::EUR, Currency::USD, "1.1681"),
+                record("2025-10-17", Currency::EUR, Currency::JPY, "175.53"),
+                record("2007-12-31", Currency::EUR, Currency::CYP, "0.585274"),
+            ],
+            records
+        );
+        assert_eq!(
+            vec![
+                LoadIssue::HistoricCode {
+                    line: 10,
+                    currency: Currency::CYP
+                },
+                LoadIssue::UnknownCode {
+                    line: 11,
+                    code: "ABC".into()
+                },
+            ],
+            issues
+        );
+        let bad = XML.replace("175.53", "-1");
+        assert_eq!(
+            Err(LoadError::InvalidRate { line: 7 }),
+            parse(&bad, RateFormat::EcbXml, &[])
+        );
+    }
+
+    #[test]
+    fn test_ecb_csv() {
+        let daily = "Date, USD, JPY, BGN, \n17 October 2025, 1.1681, 175.53, 1.9558, \n";
+        let (records, _) = parse(daily, RateFormat::EcbCsv, &[]).unwrap();
+        assert_eq!(3, records.len());
+        assert_eq!(
+            record("2025-10-17", Currency::EUR, Currency::BGN, "1.9558"),
+            records[2]
+        );
+        let hist = "Date,USD,CYP,XYZ,\n2025-10-17,1.1681,N/A,N/A,\n1999-01-04,1.1789,0.58231,2,\n";
+        let (records, issues) = parse(hist, RateFormat::EcbCsv, &[]).unwrap();
+        assert_eq!(3, records.len());
+        assert_eq!(Date::new(1999, 1, 4), Some(records[2].date));
+        assert_eq!(2, issues.len());
+        assert_eq!(
+            Err(LoadError::InvalidDate { line: 2 }),
+            parse("Date,USD\n32 May 2025,1\n", RateFormat::EcbCsv, &[])
+        );
+        assert_eq!(
+            Err(LoadError::Malformed { line: 2 }),
+            parse("Date,USD\n2025-05-01,1,2\n", RateFormat::EcbCsv, &[])
+        );
+        assert_eq!(
+            Err(LoadError::InvalidRate { line: 2 }),
+            parse("Date,EUR\n2025-05-01,1.2\n", RateFormat::EcbCsv, &[])
+        );
+    }
+
+    #[test]
+    fn test_csv() {
+        let text = "date,base,quote,rate\n# comment\n\n2025-10-17,USD,JPY,150.61\n2025-10-17,XBT,USD,100000\n";
+        let (records, issues) = parse(text, RateFormat::Csv, &[]).unwrap();
+        assert_eq!(
+            vec![record("2025-10-17", Currency::USD, Currency::JPY, "150.61")],
+            records
+        );
+        assert_eq!(
+            vec![LoadIssue::UnknownCode {
+                line: 5,
+                code: "XBT".into()
+            }],
+            issues
+        );
+        assert_eq!(Decimal::new(15061, 2), records[0].rate);
+        for (text, err) in [
+            ("2025-10-17,USD,JPY", LoadError::Malformed { line: 1 }),
+            ("17/10/2025,USD,JPY,1", LoadError::InvalidDate { line: 1 }),
+            ("2025-10-17,USD,JPY,0", LoadError::InvalidRate { line: 1 }),
+            ("2025-10-17,USD,USD,1.2", LoadError::InvalidRate { line: 1 }),
+        ] {
+            assert_eq!(Err(err), parse(text, RateFormat::Csv, &[]));
+        }
+    }
+}
@@ -0,0 +1,101 @@
+//! Allocation-free lookups backing `from_code`, `from_numeric` and the
+//! `FromStr` / `TryFrom` implementations.
+
+use crate::{Currency, ALL_CURRENCIES};
+
+/// Every numeric code `0..=999` mapped to its variant, built at compile time.
+static NUMERIC_TABLE: [Option<Currency>; 1000] = {
+    let mut table = [None; 1000];
+    let mut i = 0;
+    while i < table.len() {
+        table[i] = Currency::from_numeric(i as u16);
+        i += 1;
+    }
+    table
+};
+
+/// Every variant sorted by alpha code, built at compile time.
+pub(crate) static ALPHA_ORDER: [Currency; ALL_CURRENCIES.len()] = {
+    let mut order = [ALL_CURRENCIES[0]; ALL_CURRENCIES.len()];
+    let mut i = 0;
+    while i < order.len() {
+        order[i] = ALL_CURRENCIES[i];
+        i += 1;
+    }
+    i = 1;
+    while i < order.len() {
+        let mut j = i;
+        while j > 0 && alpha_before(order[j], order[j - 1]) {
+            let swap = order[j];
+            order[j] = order[j - 1];
+            order[j - 1] = swap;
+            j -= 1;
+        }
+        i += 1;
+    }
+    order
+};
+
+const fn alpha_before(lhs: Currency, rhs: Currency) -> bool {
+    let (lhs, rhs) = (lhs.code().as_bytes(), rhs.code().as_bytes());
+    let mut i = 0;
+    while i < lhs.len() {
+        if lhs[i] != rhs[i] {
+            return lhs[i] < rhs[i];
+        }
+        i += 1;
+    }
+    false
+}
+
+/// Matches a 3 byte alpha code in any ASCII case.
+#[inline]
+pub(crate) fn alpha3(code: &[u8]) -> Option<Currency> {
+    match code {
+        [a, b, c] => Currency::from_alpha3(&[
+            a.to_ascii_uppercase(),
+            b.to_ascii_uppercase(),
+            c.to_ascii_uppercase(),
+        ]),
+        _ => None,
+    }
+}
+
+/// Indexes the numeric table; `None` above 999 or for unassigned codes.
+#[inline]
+pub(crate) fn numeric(numeric: u16) -> Option<Currency> {
+    NUMERIC_TABLE.get(numeric as usize).copied().flatten()
+}
+
+/// Parses exactly 3 ASCII digits, e.g. `b"008"`.
+#[inline]
+pub(crate) fn numeric_digits(digits: &[u8]) -> Option<u16> {
+    match digits {
+        [a, b, c] if digits.iter().all(u8::is_ascii_digit) => {
+            Some((a - b'0') as u16 * 100 + (b - b'0') as u16 * 10 + (c - b'0') as u16)
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_tables_match_maps() {
+        for (key, code) in crate::ALL_MAP.entries() {
+            assert_eq!(
+                Some(code.code),
+                super::alpha3(key.as_bytes()).map(|c| c.code())
+            );
+        }
+        assert!(super::ALPHA_ORDER
+            .windows(2)
+            .all(|pair| pair[0].code() < pair[1].code()));
+        for (key, code) in crate::NUMERIC_MAP.entries() {
+            let numeric = super::numeric_digits(key.as_bytes()).unwrap();
+            assert_eq!(Some(code.code), super::numeric(numeric).map(|c| c.code()));
+        }
+        let assigned = (0..1000).filter_map(super::numeric).count();
+        assert_eq!(crate::NUMERIC_MAP.len(), assigned);
+    }
+}
@@ -0,0 +1,87 @@
+/// Resolves an ISO 4217 alpha or numeric literal to its
+/// [`CurrencyCode`](crate::CurrencyCode) at compile time.
+///
+/// Unknown and historic codes are rejected during constant evaluation, so a
+/// typo fails the build instead of panicking at runtime.
+/// #Sample
+/// ```
+/// use rust_iso4217::{currency, CurrencyCode};
+///
+/// const USD: CurrencyCode = currency!("USD");
+/// assert_eq!(840, USD.numeric);
+/// assert_eq!("EUR", currency!(978).code);
+/// assert_eq!("JPY", currency!("jpy").code);
+/// ```
+///
+/// ```compile_fail
+/// let typo = rust_iso4217::currency!("UDS");
+/// ```
+///
+/// ```compile_fail
+/// let withdrawn = rust_iso4217::currency!("DEM");
+/// ```
+#[macro_export]
+macro_rules! currency {
+    ($code:literal) => {{
+        const CODE: $crate::CurrencyCode = *$crate::__private::Literal($code)
+            .resolve()
+            .currency_code();
+        CODE
+    }};
+}
+
+#[doc(hidden)]
+pub mod __private {
+    use crate::{CodeType, Currency};
+
+    /// Wraps the `currency!` literal so string and integer literals can be
+    /// told apart by method resolution.
+    pub struct Literal<T>(pub T);
+
+    impl Literal<&'static str> {
+        pub const fn resolve(self) -> Currency {
+            let bytes = self.0.as_bytes();
+            if bytes.len() != 3 {
+                panic!("currency!: ISO 4217 codes are 3 characters long");
+            }
+            let currency = if bytes[0].is_ascii_digit()
+                && bytes[1].is_ascii_digit()
+                && bytes[2].is_ascii_digit()
+            {
+                Currency::from_numeric(
+                    (bytes[0] - b'0') as u16 * 100
+                        + (bytes[1] - b'0') as u16 * 10
+                        + (bytes[2] - b'0') as u16,
+                )
+            } else {
+                Currency::from_alpha3(&[
+                    bytes[0].to_ascii_uppercase(),
+                    bytes[1].to_ascii_uppercase(),
+                    bytes[2].to_ascii_uppercase(),
+                ])
+            };
+            active(currency)
+        }
+    }
+
+    impl Literal<i32> {
+        pub const fn resolve(self) -> Currency {
+            if self.0 < 0 || self.0 > 999 {
+                panic!("currency!: ISO 4217 numeric codes are within 0..=999");
+            }
+            active(Currency::from_numeric(self.0 as u16))
+        }
+    }
+
+    const fn active(currency: Option<Currency>) -> Currency {
+        match currency {
+            Some(currency) => {
+                if matches!(currency.code_type(), CodeType::Historic) {
+                    panic!("currency!: historic ISO 4217 code");
+                }
+                currency
+            }
+            None => panic!("currency!: unknown ISO 4217 code"),
+        }
+    }
+}
@@ -0,0 +1,110 @@
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen::prelude::*;
+
+use crate::{Currency, CurrencyCode, MinorUnitError};
+
+/// Typed minor unit accessors; the raw `unit` keeps `-1` for `N.A.`.
+/// #Sample
+/// ```
+/// assert_eq!(Some(3), rust_iso4217::KWD.minor_unit());
+/// assert_eq!(Some(1000), rust_iso4217::KWD.minor_unit_factor());
+/// assert_eq!(None, rust_iso4217::XAU.minor_unit());
+/// ```
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+impl CurrencyCode {
+    /// Number of decimals of the minor unit, `None` when not applicable.
+    pub fn minor_unit(&self) -> Option<u8> {
+        u8::try_from(self.unit).ok()
+    }
+
+    /// Minor units per major unit (`10^minor_unit`), `None` when not applicable.
+    pub fn minor_unit_factor(&self) -> Option<i64> {
+        self.minor_unit().map(|unit| 10_i64.pow(unit as u32))
+    }
+}
+
+impl CurrencyCode {
+    /// Converts a whole number of major units into minor units.
+    /// #Sample
+    /// ```
+    /// assert_eq!(Ok(1500), rust_iso4217::USD.major_to_minor(15));
+    /// assert_eq!(Ok(15), rust_iso4217::JPY.major_to_minor(15));
+    /// ```
+    pub fn major_to_minor(&self, major: i64) -> Result<i64, MinorUnitError> {
+        let factor = self
+            .minor_unit_factor()
+            .ok_or(MinorUnitError::NoMinorUnit)?;
+        major.checked_mul(factor).ok_or(MinorUnitError::Overflow)
+    }
+
+    /// Splits an amount of minor units into whole major units and the
+    /// remaining minor units, both carrying the sign of `minor`.
+    /// #Sample
+    /// ```
+    /// assert_eq!(Ok((12, 345)), rust_iso4217::BHD.minor_to_major(12_345));
+    /// assert_eq!(Ok((-1, -5)), rust_iso4217::EUR.minor_to_major(-105));
+    /// ```
+    pub fn minor_to_major(&self, minor: i64) -> Result<(i64, i64), MinorUnitError> {
+        let factor = self
+            .minor_unit_factor()
+            .ok_or(MinorUnitError::NoMinorUnit)?;
+        Ok((minor / factor, minor % factor))
+    }
+}
+
+impl Currency {
+    /// See [`CurrencyCode::minor_unit`].
+    pub fn minor_unit(self) -> Option<u8> {
+        self.currency_code().minor_unit()
+    }
+
+    /// See [`CurrencyCode::minor_unit_factor`].
+    pub fn minor_unit_factor(self) -> Option<i64> {
+        self.currency_code().minor_unit_factor()
+    }
+
+    /// See [`CurrencyCode::major_to_minor`].
+    pub fn major_to_minor(self, major: i64) -> Result<i64, MinorUnitError> {
+        self.currency_code().major_to_minor(major)
+    }
+
+    /// See [`CurrencyCode::minor_to_major`].
+    pub fn minor_to_major(self, minor: i64) -> Result<(i64, i64), MinorUnitError> {
+        self.currency_code().minor_to_major(minor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::MinorUnitError;
+
+    #[test]
+    fn test_minor_unit() {
+        for code in crate::ALL_CODES {
+            match code.minor_unit() {
+                Some(unit) => assert_eq!(code.unit, unit as i32),
+                None => assert_eq!(-1, code.unit),
+            }
+        }
+        assert_eq!(Some(10_000), crate::CLF.minor_unit_factor());
+        assert_eq!(Some(1), crate::JPY.minor_unit_factor());
+        assert_eq!(None, crate::DEM.minor_unit_factor());
+    }
+
+    #[test]
+    fn test_conversions() {
+        assert_eq!(
+            Err(MinorUnitError::NoMinorUnit),
+            crate::XXX.major_to_minor(1)
+        );
+        assert_eq!(
+            Err(MinorUnitError::NoMinorUnit),
+            crate::XAU.minor_to_major(1)
+        );
+        assert_eq!(
+            Err(MinorUnitError::Overflow),
+            crate::USD.major_to_minor(i64::MAX)
+        );
+        assert_eq!(Ok((0, 99)), crate::USD.minor_to_major(99));
+    }
+}
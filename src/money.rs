@@ -0,0 +1,344 @@
+use core::cmp::Ordering;
+use core::fmt;
+use core::str::FromStr;
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use crate::{CurrencyCode, Decimal, MoneyError};
+
+/// An amount held as an integer number of minor units of a currency.
+///
+/// Only currencies with a minor unit can hold amounts; `XAU`, `XXX` and the
+/// historic codes are rejected with [`MoneyError::NoMinorUnit`].
+/// #Sample
+/// ```
+/// use rust_iso4217::Money;
+///
+/// let price = Money::from_decimal_str("19.99", rust_iso4217::USD).unwrap();
+/// let shipping = Money::from_minor(500, rust_iso4217::USD).unwrap();
+/// let total = price.checked_add(shipping).unwrap();
+/// assert_eq!(2499, total.amount());
+/// assert_eq!("24.99 USD", total.to_string());
+/// assert!(price.checked_add(Money::from_major(1, rust_iso4217::EUR).unwrap()).is_err());
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Money {
+    amount: i64,
+    currency: CurrencyCode,
+}
+
+impl Money {
+    /// Caller checked that `currency` has a minor unit.
+    pub(crate) const fn new(amount: i64, currency: CurrencyCode) -> Money {
+        Money { amount, currency }
+    }
+
+    /// Creates an amount of `amount` minor units, e.g. cents.
+    pub fn from_minor(amount: i64, currency: CurrencyCode) -> Result<Money, MoneyError> {
+        currency.minor_unit().ok_or(MoneyError::NoMinorUnit)?;
+        Ok(Money { amount, currency })
+    }
+
+    /// Creates an amount of `major` whole units, e.g. dollars.
+    pub fn from_major(major: i64, currency: CurrencyCode) -> Result<Money, MoneyError> {
+        let factor = currency
+            .minor_unit_factor()
+            .ok_or(MoneyError::NoMinorUnit)?;
+        let amount = major.checked_mul(factor).ok_or(MoneyError::Overflow)?;
+        Ok(Money { amount, currency })
+    }
+
+    pub fn zero(currency: CurrencyCode) -> Result<Money, MoneyError> {
+        Money::from_minor(0, currency)
+    }
+
+    /// Parses a decimal amount such as `"-1234.5"` in `currency`.
+    ///
+    /// Decimals beyond the minor unit are only accepted when they are zeros.
+    pub fn from_decimal_str(s: &str, currency: CurrencyCode) -> Result<Money, MoneyError> {
+        let unit = currency.minor_unit().ok_or(MoneyError::NoMinorUnit)?;
+        let decimal: Decimal = s.parse()?;
+        let amount = decimal
+            .rescale(unit as u32)
+            .ok_or(MoneyError::TooManyDecimals)?;
+        let amount = i64::try_from(amount).map_err(|_| MoneyError::Overflow)?;
+        Ok(Money { amount, currency })
+    }
+
+    /// Amount in minor units.
+    pub const fn amount(&self) -> i64 {
+        self.amount
+    }
+
+    pub const fn currency(&self) -> CurrencyCode {
+        self.currency
+    }
+
+    /// Number of decimals of the currency's minor unit.
+    pub fn minor_unit(&self) -> u8 {
+        // Checked on construction.
+        self.currency.minor_unit().unwrap_or(0)
+    }
+
+    pub const fn is_zero(&self) -> bool {
+        self.amount == 0
+    }
+
+    pub const fn is_positive(&self) -> bool {
+        self.amount > 0
+    }
+
+    pub const fn is_negative(&self) -> bool {
+        self.amount < 0
+    }
+
+    /// Same currency with a new amount of minor units.
+    pub const fn with_amount(&self, amount: i64) -> Money {
+        Money {
+            amount,
+            currency: self.currency,
+        }
+    }
+
+    pub fn checked_add(self, rhs: Money) -> Result<Money, MoneyError> {
+        self.same_currency(&rhs)?;
+        let amount = self.amount.checked_add(rhs.amount);
+        Ok(self.with_amount(amount.ok_or(MoneyError::Overflow)?))
+    }
+
+    pub fn checked_sub(self, rhs: Money) -> Result<Money, MoneyError> {
+        self.same_currency(&rhs)?;
+        let amount = self.amount.checked_sub(rhs.amount);
+        Ok(self.with_amount(amount.ok_or(MoneyError::Overflow)?))
+    }
+
+    pub fn checked_neg(self) -> Result<Money, MoneyError> {
+        let amount = self.amount.checked_neg();
+        Ok(self.with_amount(amount.ok_or(MoneyError::Overflow)?))
+    }
+
+    /// Multiplies by an integer factor, e.g. a quantity.
+    pub fn checked_mul(self, factor: i64) -> Result<Money, MoneyError> {
+        let amount = self.amount.checked_mul(factor);
+        Ok(self.with_amount(amount.ok_or(MoneyError::Overflow)?))
+    }
+
+    /// Splits the amount across `ratios` without creating or losing a minor
+    /// unit.
+    ///
+    /// Each share is rounded toward zero; the units left over go one each to
+    /// the shares with the largest remainders, earlier shares first on ties.
+    /// #Sample
+    /// ```
+    /// use rust_iso4217::Money;
+    ///
+    /// let fee = Money::from_decimal_str("0.05", rust_iso4217::EUR).unwrap();
+    /// let shares = fee.allocate(&[70, 20, 10]).unwrap();
+    /// assert_eq!(vec![4, 1, 0], shares.iter().map(Money::amount).collect::<Vec<_>>());
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn allocate(&self, ratios: &[u64]) -> Result<Vec<Money>, MoneyError> {
+        let total: u128 = ratios.iter().map(|r| *r as u128).sum();
+        if total == 0 {
+            return Err(MoneyError::InvalidRatios);
+        }
+        let amount = self.amount.unsigned_abs() as u128;
+        let mut shares: Vec<(u128, u128)> = ratios
+            .iter()
+            .map(|r| {
+                // |i64| * u64 fits in a u128.
+                let product = amount * *r as u128;
+                (product / total, product % total)
+            })
+            .collect();
+        let left = amount - shares.iter().map(|(share, _)| share).sum::<u128>();
+        let mut order: Vec<usize> = (0..shares.len()).collect();
+        // Stable sort keeps earlier shares first among equal remainders.
+        order.sort_by(|a, b| shares[*b].1.cmp(&shares[*a].1));
+        for index in order.into_iter().take(left as usize) {
+            shares[index].0 += 1;
+        }
+        Ok(shares
+            .into_iter()
+            .map(|(share, _)| {
+                // Every share is at most |amount|, so it fits back in an i64.
+                let share = share as i128 * self.amount.signum() as i128;
+                self.with_amount(share as i64)
+            })
+            .collect())
+    }
+
+    /// Splits the amount into `n` shares differing by at most one minor unit,
+    /// larger shares first. `n` must be between 1 and `i64::MAX`.
+    #[cfg(feature = "alloc")]
+    pub fn split(&self, n: usize) -> Result<Vec<Money>, MoneyError> {
+        if n == 0 {
+            return Err(MoneyError::InvalidRatios);
+        }
+        let n = i64::try_from(n).map_err(|_| MoneyError::InvalidRatios)?;
+        let (share, left) = (self.amount / n, self.amount % n);
+        Ok((0..n)
+            .map(|i| {
+                let extra = if i < left.abs() { left.signum() } else { 0 };
+                self.with_amount(share + extra)
+            })
+            .collect())
+    }
+
+    /// Displays the decimal amount without the currency code, e.g. `12.30`.
+    pub fn decimal(&self) -> impl fmt::Display {
+        self.to_decimal()
+    }
+
+    pub(crate) fn same_currency(&self, other: &Money) -> Result<(), MoneyError> {
+        if self.currency.code == other.currency.code {
+            Ok(())
+        } else {
+            Err(MoneyError::CurrencyMismatch(
+                self.currency.code,
+                other.currency.code,
+            ))
+        }
+    }
+}
+
+/// Amounts in different currencies are unordered.
+impl PartialOrd for Money {
+    fn partial_cmp(&self, other: &Money) -> Option<Ordering> {
+        self.same_currency(other).ok()?;
+        Some(self.amount.cmp(&other.amount))
+    }
+}
+
+/// Formats as `"<amount> <code>"`, e.g. `-0.05 EUR`.
+impl fmt::Display for Money {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.decimal(), self.currency.code)
+    }
+}
+
+/// Parses the `Display` form, e.g. `"12.34 USD"`.
+impl FromStr for Money {
+    type Err = MoneyError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (amount, code) = s.split_once(' ').ok_or(MoneyError::InvalidAmount)?;
+        Money::from_decimal_str(amount, code.parse()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Money, MoneyError};
+
+    #[test]
+    fn test_decimal_round_trip() {
+        for (s, code, minor) in [
+            ("12.34", crate::USD, 1234),
+            ("-0.05", crate::EUR, -5),
+            ("1000", crate::JPY, 1000),
+            ("1.234", crate::KWD, 1234),
+            ("0.0001", crate::CLF, 1),
+        ] {
+            let money = Money::from_decimal_str(s, code).unwrap();
+            assert_eq!(minor, money.amount());
+            assert_eq!(s, money.decimal().to_string());
+            assert_eq!(Ok(money), money.to_string().parse());
+        }
+        let money = Money::from_decimal_str("+7.100", crate::USD).unwrap();
+        assert_eq!(710, money.amount());
+        let min = Money::from_minor(i64::MIN, crate::USD).unwrap();
+        assert_eq!("-92233720368547758.08 USD", min.to_string());
+    }
+
+    #[test]
+    fn test_rejections() {
+        assert_eq!(Err(MoneyError::NoMinorUnit), Money::zero(crate::XAU));
+        assert_eq!(
+            Err(MoneyError::NoMinorUnit),
+            Money::from_major(1, crate::DEM)
+        );
+        for s in ["", "-", "1.", ".5", "1.2.3", "1,5", "abc", " 1"] {
+            assert_eq!(
+                Err(MoneyError::InvalidAmount),
+                Money::from_decimal_str(s, crate::USD),
+                "{:?}",
+                s
+            );
+        }
+        assert_eq!(
+            Err(MoneyError::TooManyDecimals),
+            Money::from_decimal_str("1.5", crate::JPY)
+        );
+        assert_eq!(
+            Err(MoneyError::Overflow),
+            Money::from_decimal_str("92233720368547758.08", crate::USD)
+        );
+        assert!(matches!(
+            "1.00 UDS".parse::<Money>(),
+            Err(MoneyError::Currency(_))
+        ));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_allocation() {
+        let amounts = |shares: Vec<Money>| shares.iter().map(Money::amount).collect::<Vec<_>>();
+        let bill = Money::from_major(100, crate::EUR).unwrap();
+        assert_eq!(vec![3334, 3333, 3333], amounts(bill.split(3).unwrap()));
+        assert_eq!(
+            vec![3334, 3333, 3333],
+            amounts(bill.allocate(&[1, 1, 1]).unwrap())
+        );
+        let yen = Money::from_major(100, crate::JPY).unwrap();
+        assert_eq!(vec![34, 33, 33], amounts(yen.split(3).unwrap()));
+        assert_eq!(vec![67, 33], amounts(yen.allocate(&[2, 1]).unwrap()));
+        let dinar = Money::from_decimal_str("1.000", crate::KWD).unwrap();
+        assert_eq!(
+            vec![143, 143, 143, 143, 143, 143, 142],
+            amounts(dinar.split(7).unwrap())
+        );
+        let uf = Money::from_decimal_str("-0.0010", crate::CLF).unwrap();
+        assert_eq!(vec![-3, -3, -2, -2], amounts(uf.split(4).unwrap()));
+        // Remainders 0.5, 0.5, 0.0: ties go to the earlier share.
+        let odd = Money::from_minor(-5, crate::USD).unwrap();
+        assert_eq!(vec![-3, -2, 0], amounts(odd.allocate(&[1, 1, 0]).unwrap()));
+        let max = Money::from_minor(i64::MIN, crate::USD).unwrap();
+        let shares = max.allocate(&[u64::MAX, 1]).unwrap();
+        assert_eq!(i64::MIN, shares[0].amount() + shares[1].amount());
+        assert_eq!(Err(MoneyError::InvalidRatios), bill.allocate(&[]));
+        assert_eq!(Err(MoneyError::InvalidRatios), bill.allocate(&[0, 0]));
+        assert_eq!(Err(MoneyError::InvalidRatios), bill.split(0));
+        #[cfg(target_pointer_width = "64")]
+        {
+            assert_eq!(Err(MoneyError::InvalidRatios), bill.split(usize::MAX));
+            assert_eq!(Err(MoneyError::InvalidRatios), max.split(usize::MAX));
+        }
+        assert_eq!(vec![i64::MIN], amounts(max.split(1).unwrap()));
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let a = Money::from_minor(150, crate::EUR).unwrap();
+        let b = Money::from_minor(-50, crate::EUR).unwrap();
+        assert_eq!(100, a.checked_add(b).unwrap().amount());
+        assert_eq!(200, a.checked_sub(b).unwrap().amount());
+        assert_eq!(-150, a.checked_neg().unwrap().amount());
+        assert_eq!(450, a.checked_mul(3).unwrap().amount());
+        assert!(a > b);
+        let usd = Money::from_minor(1, crate::USD).unwrap();
+        assert_eq!(
+            Err(MoneyError::CurrencyMismatch("EUR", "USD")),
+            a.checked_add(usd)
+        );
+        assert_eq!(None, a.partial_cmp(&usd));
+        let max = Money::from_minor(i64::MAX, crate::EUR).unwrap();
+        assert_eq!(Err(MoneyError::Overflow), max.checked_add(a));
+        assert_eq!(Err(MoneyError::Overflow), max.checked_mul(2));
+        assert_eq!(
+            Err(MoneyError::Overflow),
+            max.with_amount(i64::MIN).checked_neg()
+        );
+    }
+}
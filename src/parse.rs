@@ -0,0 +1,177 @@
+use core::str::FromStr;
+
+use crate::lookup;
+use crate::{Currency, CurrencyCode, ParseCurrencyError, ALL_CODES};
+
+/// Parses an alpha code (any ASCII case) or a 3 digit numeric code.
+impl FromStr for Currency {
+    type Err = ParseCurrencyError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if !s.is_ascii() {
+            return Err(ParseCurrencyError::NonAscii);
+        }
+        let bytes = s.as_bytes();
+        if bytes.len() != 3 {
+            return Err(ParseCurrencyError::WrongLength(bytes.len()));
+        }
+        if let Some(numeric) = lookup::numeric_digits(bytes) {
+            return Currency::try_from(numeric);
+        }
+        lookup::alpha3(bytes).ok_or_else(|| ParseCurrencyError::UnknownCode {
+            suggestion: suggest(&[
+                bytes[0].to_ascii_uppercase(),
+                bytes[1].to_ascii_uppercase(),
+                bytes[2].to_ascii_uppercase(),
+            ]),
+        })
+    }
+}
+
+impl TryFrom<&str> for Currency {
+    type Error = ParseCurrencyError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl TryFrom<u16> for Currency {
+    type Error = ParseCurrencyError;
+
+    fn try_from(numeric: u16) -> Result<Self, Self::Error> {
+        if numeric > 999 {
+            return Err(ParseCurrencyError::OutOfRange(numeric as i32));
+        }
+        lookup::numeric(numeric).ok_or(ParseCurrencyError::UnknownNumeric(numeric))
+    }
+}
+
+impl TryFrom<i32> for Currency {
+    type Error = ParseCurrencyError;
+
+    fn try_from(numeric: i32) -> Result<Self, Self::Error> {
+        match u16::try_from(numeric) {
+            Ok(n) if n <= 999 => Currency::try_from(n),
+            _ => Err(ParseCurrencyError::OutOfRange(numeric)),
+        }
+    }
+}
+
+/// Parses an alpha code (any ASCII case) or a 3 digit numeric code.
+impl FromStr for CurrencyCode {
+    type Err = ParseCurrencyError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse::<Currency>().map(Currency::into)
+    }
+}
+
+impl TryFrom<&str> for CurrencyCode {
+    type Error = ParseCurrencyError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl TryFrom<u16> for CurrencyCode {
+    type Error = ParseCurrencyError;
+
+    fn try_from(numeric: u16) -> Result<Self, Self::Error> {
+        Currency::try_from(numeric).map(Currency::into)
+    }
+}
+
+impl TryFrom<i32> for CurrencyCode {
+    type Error = ParseCurrencyError;
+
+    fn try_from(numeric: i32) -> Result<Self, Self::Error> {
+        Currency::try_from(numeric).map(Currency::into)
+    }
+}
+
+/// Finds a known code one adjacent swap (`UDS` → `USD`) or one letter away
+/// from `up`. Swaps win over substitutions, then `ALL_CODES` order applies,
+/// which lists active codes before historic ones.
+fn suggest(up: &[u8; 3]) -> Option<&'static str> {
+    let codes = || ALL_CODES.iter().map(|c| c.code);
+    let swapped = |code: &[u8]| {
+        (code[0] == up[1] && code[1] == up[0] && code[2] == up[2])
+            || (code[0] == up[0] && code[1] == up[2] && code[2] == up[1])
+    };
+    let one_off = |code: &[u8]| code.iter().zip(up).filter(|(a, b)| a != b).count() == 1;
+    codes()
+        .find(|c| swapped(c.as_bytes()))
+        .or_else(|| codes().find(|c| one_off(c.as_bytes())))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{CurrencyCode, ParseCurrencyError};
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(Ok(crate::USD), "usd".parse::<CurrencyCode>());
+        assert_eq!(Ok(crate::USD), "840".parse::<CurrencyCode>());
+        assert_eq!(Ok(crate::ALL), CurrencyCode::try_from("008"));
+        assert_eq!(
+            Err(ParseCurrencyError::WrongLength(4)),
+            "US D".parse::<CurrencyCode>()
+        );
+        assert_eq!(
+            Err(ParseCurrencyError::WrongLength(4)),
+            "usd ".parse::<CurrencyCode>()
+        );
+        assert_eq!(
+            Err(ParseCurrencyError::WrongLength(2)),
+            "12".parse::<CurrencyCode>()
+        );
+        assert_eq!(
+            Err(ParseCurrencyError::NonAscii),
+            "€UR".parse::<CurrencyCode>()
+        );
+        assert_eq!(
+            Err(ParseCurrencyError::UnknownNumeric(1)),
+            "001".parse::<CurrencyCode>()
+        );
+    }
+
+    #[test]
+    fn test_suggestion() {
+        assert_eq!(
+            Err(ParseCurrencyError::UnknownCode {
+                suggestion: Some("USD")
+            }),
+            "UDS".parse::<CurrencyCode>()
+        );
+        assert_eq!(
+            Err(ParseCurrencyError::UnknownCode {
+                suggestion: Some("GBP")
+            }),
+            "gpb".parse::<CurrencyCode>()
+        );
+        assert_eq!(
+            Err(ParseCurrencyError::UnknownCode { suggestion: None }),
+            "QQQ".parse::<CurrencyCode>()
+        );
+    }
+
+    #[test]
+    fn test_try_from_numeric() {
+        assert_eq!(Ok(crate::EUR), CurrencyCode::try_from(978_i32));
+        assert_eq!(Ok(crate::EUR), CurrencyCode::try_from(978_u16));
+        assert_eq!(
+            Err(ParseCurrencyError::OutOfRange(-1)),
+            CurrencyCode::try_from(-1_i32)
+        );
+        assert_eq!(
+            Err(ParseCurrencyError::OutOfRange(1000)),
+            CurrencyCode::try_from(1000_u16)
+        );
+        assert_eq!(
+            Err(ParseCurrencyError::UnknownNumeric(1)),
+            CurrencyCode::try_from(1_i32)
+        );
+    }
+}
@@ -0,0 +1,224 @@
+use crate::{CurrencyCode, CurrencySet, Date, ExchangeRate};
+#[cfg(feature = "alloc")]
+use crate::{LoadError, LoadIssue, ParseCurrencyError, RateFormat, RateSeries};
+
+/// A source of exchange rates.
+///
+/// Implementations return rates only for the pairs they actually quote; they
+/// are not expected to invert or chain rates.
+pub trait RateProvider {
+    /// The most recent `base/quote` rate.
+    fn rate(&self, base: CurrencyCode, quote: CurrencyCode) -> Option<ExchangeRate>;
+
+    /// The `base/quote` rate published on `date`.
+    fn rate_on(&self, base: CurrencyCode, quote: CurrencyCode, date: Date) -> Option<ExchangeRate>;
+
+    /// Name of the source, recorded in [`Conversion`](crate::Conversion)s.
+    fn name(&self) -> &str {
+        ""
+    }
+
+    /// Currencies quoted against `base`, on any date.
+    ///
+    /// Used to route through intermediate currencies; providers that cannot
+    /// enumerate their pairs keep the empty default, and are then only
+    /// asked for the requested pair and its inverse.
+    fn quotes(&self, base: CurrencyCode) -> CurrencySet {
+        let _ = base;
+        CurrencySet::new()
+    }
+}
+
+impl<P: RateProvider + ?Sized> RateProvider for &P {
+    fn rate(&self, base: CurrencyCode, quote: CurrencyCode) -> Option<ExchangeRate> {
+        (**self).rate(base, quote)
+    }
+
+    fn rate_on(&self, base: CurrencyCode, quote: CurrencyCode, date: Date) -> Option<ExchangeRate> {
+        (**self).rate_on(base, quote, date)
+    }
+
+    fn name(&self) -> &str {
+        (**self).name()
+    }
+
+    fn quotes(&self, base: CurrencyCode) -> CurrencySet {
+        (**self).quotes(base)
+    }
+}
+
+/// Daily rates loaded from offline files, for air-gapped environments.
+///
+/// Every code is checked against the ISO 4217 table: unknown codes are
+/// skipped and historic ones kept, and both are reported by
+/// [`OfflineRates::issues`]. Rates carry the Unix timestamp of midnight UTC
+/// on their date. `rate_on` only answers for dates with a published rate; see
+/// [`RateSeries`] for rates in effect between publications.
+/// #Sample
+/// ```
+/// use rust_iso4217::{Date, OfflineRates, RateFormat, RateProvider};
+///
+/// let mut rates = OfflineRates::new();
+/// rates
+///     .load("Date,USD,JPY,\n2025-10-16,1.1697,176.35,\n2025-10-17,1.1681,175.53,\n", RateFormat::EcbCsv)
+///     .unwrap();
+/// let latest = rates.rate(rust_iso4217::EUR, rust_iso4217::USD).unwrap();
+/// assert_eq!("EUR/USD 1.1681", latest.to_string());
+/// let day = Date::new(2025, 10, 16).unwrap();
+/// assert!(rates.rate_on(rust_iso4217::EUR, rust_iso4217::JPY, day).is_some());
+/// assert!(rates.rate(rust_iso4217::USD, rust_iso4217::EUR).is_none());
+/// ```
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct OfflineRates {
+    series: RateSeries,
+}
+
+#[cfg(feature = "alloc")]
+impl OfflineRates {
+    pub fn new() -> OfflineRates {
+        OfflineRates::default()
+    }
+
+    /// Sets the name reported by [`RateProvider::name`], e.g. `"ECB"`.
+    pub fn with_name(self, name: &str) -> OfflineRates {
+        OfflineRates {
+            series: self.series.with_name(name),
+        }
+    }
+
+    /// Loads every rate in `text`, returning how many were read.
+    ///
+    /// On error nothing is loaded. Rates already present for the same pair
+    /// and date are replaced.
+    pub fn load(&mut self, text: &str, format: RateFormat) -> Result<usize, LoadError> {
+        self.series.load(text, format)
+    }
+
+    /// Reads and loads the file at `path`.
+    #[cfg(feature = "std")]
+    pub fn load_file(
+        &mut self,
+        path: impl AsRef<std::path::Path>,
+        format: RateFormat,
+    ) -> Result<usize, LoadError> {
+        self.series.load_file(path, format)
+    }
+
+    /// Adds `rate` as published on `date`.
+    pub fn insert(&mut self, date: Date, rate: ExchangeRate) -> Result<(), ParseCurrencyError> {
+        self.series.insert(date, rate)
+    }
+
+    /// Unknown and historic codes met while loading, in order.
+    pub fn issues(&self) -> &[LoadIssue] {
+        self.series.issues()
+    }
+
+    /// Number of rates held, counting each pair and date once.
+    pub fn len(&self) -> usize {
+        self.series.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.series.is_empty()
+    }
+
+    /// The loaded rates as a time series.
+    pub fn into_series(self) -> RateSeries {
+        self.series
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl RateProvider for OfflineRates {
+    fn rate(&self, base: CurrencyCode, quote: CurrencyCode) -> Option<ExchangeRate> {
+        self.series.latest(base, quote)
+    }
+
+    fn rate_on(&self, base: CurrencyCode, quote: CurrencyCode, date: Date) -> Option<ExchangeRate> {
+        self.series.get(base, quote, date)
+    }
+
+    fn name(&self) -> &str {
+        self.series.name()
+    }
+
+    fn quotes(&self, base: CurrencyCode) -> CurrencySet {
+        self.series.quotes(base)
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::{
+        Date, Decimal, ExchangeRate, LoadError, LoadIssue, OfflineRates, RateFormat, RateProvider,
+    };
+
+    #[test]
+    fn test_offline_rates() {
+        let mut rates = OfflineRates::new();
+        let csv = "date,base,quote,rate\n2025-10-17,USD,JPY,150.61\n2025-10-16,USD,JPY,151.02\n2025-10-17,USD,TRL,1\n";
+        assert_eq!(Ok(3), rates.load(csv, RateFormat::Csv));
+        assert_eq!(
+            vec![LoadIssue::HistoricCode {
+                line: 4,
+                currency: crate::Currency::TRL
+            }],
+            rates.issues()
+        );
+        let latest = rates.rate(crate::USD, crate::JPY).unwrap();
+        assert_eq!(Decimal::new(15061, 2), latest.rate());
+        assert_eq!(Some(1_760_659_200), latest.timestamp());
+        let day = Date::new(2025, 10, 16).unwrap();
+        assert_eq!(
+            Some(Decimal::new(15102, 2)),
+            rates.rate_on(crate::USD, crate::JPY, day).map(|r| r.rate())
+        );
+        assert!(rates
+            .rate_on(crate::USD, crate::JPY, day.add_days(-1))
+            .is_none());
+
+        assert_eq!(
+            Err(LoadError::InvalidRate { line: 3 }),
+            rates.load(
+                "2025-10-18,USD,JPY,1\n2025-10-18,USD,ZZZ,1\n2025-10-18,USD,CHF,x\n",
+                RateFormat::Csv
+            )
+        );
+        assert_eq!(3, rates.len());
+        assert_eq!(1, rates.issues().len());
+        assert_eq!(
+            crate::CurrencySet::new()
+                .with(crate::Currency::JPY)
+                .with(crate::Currency::TRL),
+            rates.quotes(crate::USD)
+        );
+        let rate = ExchangeRate::new(crate::USD, crate::CHF, Decimal::new(79, 2)).unwrap();
+        rates.insert(day, rate).unwrap();
+        assert_eq!(
+            Some(rate.with_timestamp(day.unix_timestamp())),
+            rates.rate(crate::USD, crate::CHF)
+        );
+    }
+
+    #[test]
+    fn test_load_file() {
+        let path =
+            std::env::temp_dir().join(format!("rust_iso4217_rates_{}.xml", std::process::id()));
+        std::fs::write(
+            &path,
+            "<Cube><Cube time='2025-10-17'><Cube currency='USD' rate='1.1681'/></Cube></Cube>",
+        )
+        .unwrap();
+        let mut rates = OfflineRates::new();
+        let loaded = rates.load_file(&path, RateFormat::EcbXml);
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(Ok(1), loaded);
+        assert!(rates.rate(crate::EUR, crate::USD).is_some());
+        assert_eq!(
+            Err(LoadError::Io(std::io::ErrorKind::NotFound)),
+            rates.load_file(&path, RateFormat::EcbXml)
+        );
+    }
+}
@@ -0,0 +1,206 @@
+use core::fmt;
+
+use crate::{CurrencyCode, Decimal, Money, MoneyError, RateError, Rounded, RoundingMode};
+
+/// Units of `quote` per unit of `base`, e.g. `EUR/USD 1.0842`.
+/// #Sample
+/// ```
+/// use rust_iso4217::{convert, Decimal, ExchangeRate, Money, RoundingMode};
+///
+/// let eur_usd = ExchangeRate::new(rust_iso4217::EUR, rust_iso4217::USD, Decimal::new(10842, 4)).unwrap();
+/// let price = Money::from_decimal_str("19.99", rust_iso4217::EUR).unwrap();
+/// let converted = convert(price, &eur_usd, RoundingMode::HalfEven).unwrap();
+/// assert_eq!("21.67 USD", converted.value.to_string());
+/// assert_eq!("0.003158", converted.remainder.to_string());
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct ExchangeRate {
+    base: CurrencyCode,
+    quote: CurrencyCode,
+    rate: Decimal,
+    timestamp: Option<i64>,
+}
+
+impl ExchangeRate {
+    /// Creates a rate; `rate` must be positive, and exactly 1 when `base`
+    /// and `quote` are the same currency.
+    pub fn new(
+        base: CurrencyCode,
+        quote: CurrencyCode,
+        rate: Decimal,
+    ) -> Result<ExchangeRate, RateError> {
+        if rate.mantissa() <= 0 {
+            return Err(RateError::NonPositive);
+        }
+        if base.code == quote.code && rate.rescale(0) != Some(1) {
+            return Err(RateError::SameCurrency(base.code));
+        }
+        Ok(ExchangeRate {
+            base,
+            quote,
+            rate,
+            timestamp: None,
+        })
+    }
+
+    /// Sets when the rate was observed, in seconds since the Unix epoch.
+    pub const fn with_timestamp(self, timestamp: i64) -> ExchangeRate {
+        ExchangeRate {
+            timestamp: Some(timestamp),
+            ..self
+        }
+    }
+
+    pub const fn base(&self) -> CurrencyCode {
+        self.base
+    }
+
+    pub const fn quote(&self) -> CurrencyCode {
+        self.quote
+    }
+
+    pub const fn rate(&self) -> Decimal {
+        self.rate
+    }
+
+    /// Seconds since the Unix epoch, if known.
+    pub const fn timestamp(&self) -> Option<i64> {
+        self.timestamp
+    }
+
+    /// The `quote/base` rate, rounded to `scale` decimals.
+    pub fn invert(&self, scale: u32, mode: RoundingMode) -> Result<ExchangeRate, RateError> {
+        let rate = Decimal::new(1, 0)
+            .checked_div(self.rate, scale, mode)
+            .ok_or(RateError::Overflow)?;
+        let inverse = ExchangeRate::new(self.quote, self.base, rate)?;
+        Ok(ExchangeRate {
+            timestamp: self.timestamp,
+            ..inverse
+        })
+    }
+
+    /// Chains `A/B` and `B/C` into the exact `A/C` rate.
+    ///
+    /// The result carries the older of both timestamps.
+    pub fn cross(&self, next: &ExchangeRate) -> Result<ExchangeRate, RateError> {
+        if self.quote.code != next.base.code {
+            return Err(RateError::Unchained(self.quote.code, next.base.code));
+        }
+        let rate = self
+            .rate
+            .checked_mul(next.rate)
+            .ok_or(RateError::Overflow)?;
+        let timestamp = match (self.timestamp, next.timestamp) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (a, b) => a.or(b),
+        };
+        Ok(ExchangeRate {
+            timestamp,
+            ..ExchangeRate::new(self.base, next.quote, rate)?
+        })
+    }
+}
+
+/// Formats as `"<base>/<quote> <rate>"`, e.g. `EUR/USD 1.0842`.
+impl fmt::Display for ExchangeRate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{} {}", self.base.code, self.quote.code, self.rate)
+    }
+}
+
+/// Converts `money` from the rate's base into its quote currency, rounding to
+/// the quote currency's minor unit with `mode`.
+///
+/// The remainder is in the quote currency at the exact product's scale.
+pub fn convert(
+    money: Money,
+    rate: &ExchangeRate,
+    mode: RoundingMode,
+) -> Result<Rounded<Money>, MoneyError> {
+    if money.currency().code != rate.base.code {
+        return Err(MoneyError::CurrencyMismatch(
+            money.currency().code,
+            rate.base.code,
+        ));
+    }
+    let amount = money
+        .to_decimal()
+        .checked_mul(rate.rate)
+        .ok_or(MoneyError::Overflow)?;
+    rate.quote.round(amount, mode)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{convert, Decimal, ExchangeRate, Money, MoneyError, RateError, RoundingMode};
+
+    fn rate(base: crate::CurrencyCode, quote: crate::CurrencyCode, s: &str) -> ExchangeRate {
+        ExchangeRate::new(base, quote, s.parse().unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_rates() {
+        let eur_usd = rate(crate::EUR, crate::USD, "1.25").with_timestamp(100);
+        let usd_jpy = rate(crate::USD, crate::JPY, "150.125").with_timestamp(50);
+        let eur_jpy = eur_usd.cross(&usd_jpy).unwrap();
+        assert_eq!("EUR/JPY 187.65625", eur_jpy.to_string());
+        assert_eq!(Some(50), eur_jpy.timestamp());
+        assert_eq!(
+            Err(RateError::Unchained("JPY", "EUR")),
+            usd_jpy.cross(&eur_usd)
+        );
+        let usd_eur = eur_usd.invert(6, RoundingMode::HalfEven).unwrap();
+        assert_eq!("USD/EUR 0.800000", usd_eur.to_string());
+        assert_eq!(Some(100), usd_eur.timestamp());
+        let jpy_usd = usd_jpy.invert(8, RoundingMode::HalfEven).unwrap();
+        assert_eq!("JPY/USD 0.00666112", jpy_usd.to_string());
+        for bad in ["0", "-1.5"] {
+            assert_eq!(
+                Err(RateError::NonPositive),
+                ExchangeRate::new(crate::EUR, crate::USD, bad.parse().unwrap())
+            );
+        }
+        assert_eq!(
+            "EUR/EUR 1.00",
+            rate(crate::EUR, crate::EUR, "1.00").to_string()
+        );
+        assert_eq!(
+            Err(RateError::SameCurrency("EUR")),
+            ExchangeRate::new(crate::EUR, crate::EUR, "1.2".parse().unwrap())
+        );
+        let usd_eur = rate(crate::USD, crate::EUR, "0.8");
+        assert_eq!(
+            "EUR/EUR 1.000",
+            eur_usd.cross(&usd_eur).unwrap().to_string()
+        );
+        let usd_eur = rate(crate::USD, crate::EUR, "0.9");
+        assert_eq!(Err(RateError::SameCurrency("EUR")), eur_usd.cross(&usd_eur));
+    }
+
+    #[test]
+    fn test_convert() {
+        let usd_jpy = rate(crate::USD, crate::JPY, "150.125");
+        let usd_kwd = rate(crate::USD, crate::KWD, "0.30745");
+        let usd = Money::from_decimal_str("10.05", crate::USD).unwrap();
+        let jpy = convert(usd, &usd_jpy, RoundingMode::HalfEven).unwrap();
+        // 10.05 * 150.125 = 1508.75625
+        assert_eq!(1509, jpy.value.amount());
+        assert_eq!(Decimal::new(-24375, 5), jpy.remainder);
+        let jpy = convert(usd, &usd_jpy, RoundingMode::Floor).unwrap();
+        assert_eq!(1508, jpy.value.amount());
+        // 10.05 * 0.30745 = 3.0898725
+        let kwd = convert(usd, &usd_kwd, RoundingMode::HalfUp).unwrap();
+        assert_eq!("3.090 KWD", kwd.value.to_string());
+        let eur = Money::from_major(1, crate::EUR).unwrap();
+        assert_eq!(
+            Err(MoneyError::CurrencyMismatch("EUR", "USD")),
+            convert(eur, &usd_jpy, RoundingMode::HalfEven)
+        );
+        let gold = rate(crate::USD, crate::XAU, "0.0004");
+        assert_eq!(
+            Err(MoneyError::NoMinorUnit),
+            convert(usd, &gold, RoundingMode::HalfEven)
+        );
+    }
+}
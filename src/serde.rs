@@ -0,0 +1,893 @@
+//! `serde` support, enabled by the `serde` feature.
+//!
+//! `CurrencyCode` and `Currency` serialize as their alpha code by default.
+//! The modules below select another wire representation with
+//! `#[serde(with = "...")]`:
+//!
+//! * [`numeric`] — the ISO number, e.g. `8`.
+//! * [`numeric_str`] — the zero-padded ISO number, e.g. `"008"`.
+//! * [`object`] — every field of the struct.
+//!
+//! Deserialization validates against `ALL_MAP` / `NUMERIC_MAP` and reports
+//! [`ParseCurrencyError`](crate::ParseCurrencyError) messages.
+//!
+//! `Decimal`, `Date` and `Money` serialize as strings (`"1.0842"`,
+//! `"2025-10-17"`, `"12.34 USD"`) so no precision is lost; `ExchangeRate` and
+//! `Conversion` as objects.
+//!
+//! ```
+//! use rust_iso4217::CurrencyCode;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Trade {
+//!     currency: CurrencyCode,
+//!     #[serde(with = "rust_iso4217::serde::numeric")]
+//!     settlement: CurrencyCode,
+//! }
+//!
+//! let trade: Trade = serde_json::from_str(r#"{"currency":"usd","settlement":978}"#).unwrap();
+//! assert_eq!(rust_iso4217::EUR, trade.settlement);
+//! assert_eq!(
+//!     r#"{"currency":"USD","settlement":978}"#,
+//!     serde_json::to_string(&trade).unwrap()
+//! );
+//! ```
+
+use core::fmt;
+use core::marker::PhantomData;
+use core::str::FromStr;
+
+use ::serde::de::{self, Deserialize, Deserializer, IgnoredAny, MapAccess, SeqAccess, Visitor};
+use ::serde::ser::{Serialize, SerializeMap, SerializeSeq, SerializeStruct, Serializer};
+
+#[cfg(feature = "alloc")]
+use crate::Conversion;
+use crate::{
+    Currency, CurrencyCode, CurrencyMap, CurrencySet, Date, Decimal, ExchangeRate, Money,
+    RoundingMode,
+};
+
+/// Serialized as its alpha code, e.g. `"EUR"`.
+impl Serialize for CurrencyCode {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.code)
+    }
+}
+
+/// Accepts an alpha code in any case or a zero-padded numeric string.
+impl<'de> Deserialize<'de> for CurrencyCode {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Currency::deserialize(deserializer).map(Currency::into)
+    }
+}
+
+/// Serialized as its alpha code, e.g. `"EUR"`.
+impl Serialize for Currency {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.code())
+    }
+}
+
+impl<'de> Deserialize<'de> for Currency {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct CurrencyVisitor;
+
+        impl Visitor<'_> for CurrencyVisitor {
+            type Value = Currency;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("an ISO 4217 alpha code")
+            }
+
+            fn visit_str<E: de::Error>(self, v: &str) -> Result<Currency, E> {
+                v.parse().map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_str(CurrencyVisitor)
+    }
+}
+
+/// Serialized as a sequence of alpha codes in `ALL_CODES` order.
+impl Serialize for CurrencySet {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(self.len()))?;
+        for currency in self {
+            seq.serialize_element(&currency)?;
+        }
+        seq.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for CurrencySet {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct SetVisitor;
+
+        impl<'de> Visitor<'de> for SetVisitor {
+            type Value = CurrencySet;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a sequence of ISO 4217 alpha codes")
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<CurrencySet, A::Error> {
+                let mut set = CurrencySet::new();
+                while let Some(currency) = seq.next_element()? {
+                    set.insert(currency);
+                }
+                Ok(set)
+            }
+        }
+
+        deserializer.deserialize_seq(SetVisitor)
+    }
+}
+
+/// Serialized as a map keyed by alpha code in `ALL_CODES` order.
+impl<V: Serialize> Serialize for CurrencyMap<V> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(self.len()))?;
+        for (currency, value) in self.iter() {
+            map.serialize_entry(&currency, value)?;
+        }
+        map.end()
+    }
+}
+
+impl<'de, V: Deserialize<'de>> Deserialize<'de> for CurrencyMap<V> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct MapVisitor<V>(PhantomData<V>);
+
+        impl<'de, V: Deserialize<'de>> Visitor<'de> for MapVisitor<V> {
+            type Value = CurrencyMap<V>;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a map keyed by ISO 4217 alpha codes")
+            }
+
+            fn visit_map<A: MapAccess<'de>>(
+                self,
+                mut access: A,
+            ) -> Result<CurrencyMap<V>, A::Error> {
+                let mut map = CurrencyMap::new();
+                while let Some((currency, value)) = access.next_entry()? {
+                    if map.insert(currency, value).is_some() {
+                        return Err(de::Error::custom(format_args!(
+                            "duplicate currency {}",
+                            currency
+                        )));
+                    }
+                }
+                Ok(map)
+            }
+        }
+
+        deserializer.deserialize_map(MapVisitor(PhantomData))
+    }
+}
+
+/// Serialized as its decimal string, e.g. `"1.0842"`, keeping the scale.
+impl Serialize for Decimal {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Decimal {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(ParseVisitor("a decimal string", PhantomData))
+    }
+}
+
+/// Serialized as `"YYYY-MM-DD"`.
+impl Serialize for Date {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Date {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(ParseVisitor("a YYYY-MM-DD date", PhantomData))
+    }
+}
+
+/// Serialized as its `Display` form, e.g. `"12.34 USD"`.
+impl Serialize for Money {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Money {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(ParseVisitor("an amount like \"12.34 USD\"", PhantomData))
+    }
+}
+
+/// Deserializes a string through `FromStr`, reporting its error message.
+struct ParseVisitor<T>(&'static str, PhantomData<T>);
+
+impl<T: FromStr> Visitor<'_> for ParseVisitor<T>
+where
+    T::Err: fmt::Display,
+{
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.0)
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<T, E> {
+        v.parse().map_err(E::custom)
+    }
+}
+
+const ROUNDING_MODES: [RoundingMode; 6] = [
+    RoundingMode::HalfUp,
+    RoundingMode::HalfEven,
+    RoundingMode::HalfDown,
+    RoundingMode::Ceiling,
+    RoundingMode::Floor,
+    RoundingMode::TowardZero,
+];
+
+/// Serialized as its snake-case name, e.g. `"half_even"`.
+impl Serialize for RoundingMode {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for RoundingMode {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct ModeVisitor;
+
+        impl Visitor<'_> for ModeVisitor {
+            type Value = RoundingMode;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a rounding mode such as \"half_even\"")
+            }
+
+            fn visit_str<E: de::Error>(self, v: &str) -> Result<RoundingMode, E> {
+                ROUNDING_MODES
+                    .into_iter()
+                    .find(|mode| mode.as_str() == v)
+                    .ok_or_else(|| E::custom(format_args!("unknown rounding mode {}", v)))
+            }
+        }
+
+        deserializer.deserialize_str(ModeVisitor)
+    }
+}
+
+/// Declares a struct's field identifiers; unknown names map to `Other`.
+macro_rules! fields {
+    ($expecting:literal, $($name:literal => $field:ident),*) => {
+        enum Field {
+            $($field,)*
+            Other,
+        }
+
+        impl<'de> Deserialize<'de> for Field {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                struct FieldVisitor;
+
+                impl Visitor<'_> for FieldVisitor {
+                    type Value = Field;
+
+                    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                        f.write_str($expecting)
+                    }
+
+                    fn visit_str<E: de::Error>(self, v: &str) -> Result<Field, E> {
+                        Ok(match v {
+                            $($name => Field::$field,)*
+                            _ => Field::Other,
+                        })
+                    }
+                }
+
+                deserializer.deserialize_identifier(FieldVisitor)
+            }
+        }
+    };
+}
+
+/// Serialized as `{"base", "quote", "rate", "timestamp"}`, the timestamp
+/// being `null` when unknown.
+impl Serialize for ExchangeRate {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut object = serializer.serialize_struct("ExchangeRate", 4)?;
+        object.serialize_field("base", &self.base())?;
+        object.serialize_field("quote", &self.quote())?;
+        object.serialize_field("rate", &self.rate())?;
+        object.serialize_field("timestamp", &self.timestamp())?;
+        object.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for ExchangeRate {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        fields!("an ExchangeRate field name",
+            "base" => Base, "quote" => Quote, "rate" => Rate, "timestamp" => Timestamp);
+
+        struct RateVisitor;
+
+        impl<'de> Visitor<'de> for RateVisitor {
+            type Value = ExchangeRate;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("an ExchangeRate object")
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<ExchangeRate, A::Error> {
+                let (mut base, mut quote, mut rate, mut timestamp) = (None, None, None, None);
+                while let Some(field) = map.next_key()? {
+                    match field {
+                        Field::Base => base = Some(map.next_value()?),
+                        Field::Quote => quote = Some(map.next_value()?),
+                        Field::Rate => rate = Some(map.next_value()?),
+                        Field::Timestamp => timestamp = map.next_value()?,
+                        Field::Other => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                let rate = ExchangeRate::new(
+                    base.ok_or_else(|| de::Error::missing_field("base"))?,
+                    quote.ok_or_else(|| de::Error::missing_field("quote"))?,
+                    rate.ok_or_else(|| de::Error::missing_field("rate"))?,
+                )
+                .map_err(de::Error::custom)?;
+                Ok(match timestamp {
+                    Some(timestamp) => rate.with_timestamp(timestamp),
+                    None => rate,
+                })
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "ExchangeRate",
+            &["base", "quote", "rate", "timestamp"],
+            RateVisitor,
+        )
+    }
+}
+
+#[cfg(feature = "alloc")]
+const CONVERSION_FIELDS: &[&str] = &[
+    "input",
+    "output",
+    "rate",
+    "legs",
+    "provider",
+    "as_of",
+    "mode",
+    "remainder",
+];
+
+/// Serialized as an object with one entry per field.
+#[cfg(feature = "alloc")]
+impl Serialize for Conversion {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut object = serializer.serialize_struct("Conversion", CONVERSION_FIELDS.len())?;
+        object.serialize_field("input", &self.input)?;
+        object.serialize_field("output", &self.output)?;
+        object.serialize_field("rate", &self.rate)?;
+        object.serialize_field("legs", &self.legs)?;
+        object.serialize_field("provider", &self.provider)?;
+        object.serialize_field("as_of", &self.as_of)?;
+        object.serialize_field("mode", &self.mode)?;
+        object.serialize_field("remainder", &self.remainder)?;
+        object.end()
+    }
+}
+
+/// Restores the record as written; use [`Conversion::replay`] to check it.
+#[cfg(feature = "alloc")]
+impl<'de> Deserialize<'de> for Conversion {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        fields!("a Conversion field name",
+            "input" => Input, "output" => Output, "rate" => Rate, "legs" => Legs,
+            "provider" => Provider, "as_of" => AsOf, "mode" => Mode, "remainder" => Remainder);
+
+        struct ConversionVisitor;
+
+        impl<'de> Visitor<'de> for ConversionVisitor {
+            type Value = Conversion;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a Conversion object")
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Conversion, A::Error> {
+                let (mut input, mut output, mut rate, mut legs) = (None, None, None, None);
+                let (mut provider, mut as_of, mut mode, mut remainder) = (None, None, None, None);
+                while let Some(field) = map.next_key()? {
+                    match field {
+                        Field::Input => input = Some(map.next_value()?),
+                        Field::Output => output = Some(map.next_value()?),
+                        Field::Rate => rate = Some(map.next_value()?),
+                        Field::Legs => legs = Some(map.next_value()?),
+                        Field::Provider => provider = Some(map.next_value()?),
+                        Field::AsOf => as_of = map.next_value()?,
+                        Field::Mode => mode = Some(map.next_value()?),
+                        Field::Remainder => remainder = Some(map.next_value()?),
+                        Field::Other => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(Conversion {
+                    input: input.ok_or_else(|| de::Error::missing_field("input"))?,
+                    output: output.ok_or_else(|| de::Error::missing_field("output"))?,
+                    rate: rate.ok_or_else(|| de::Error::missing_field("rate"))?,
+                    legs: legs.ok_or_else(|| de::Error::missing_field("legs"))?,
+                    provider: provider.unwrap_or_default(),
+                    as_of,
+                    mode: mode.ok_or_else(|| de::Error::missing_field("mode"))?,
+                    remainder: remainder.ok_or_else(|| de::Error::missing_field("remainder"))?,
+                })
+            }
+        }
+
+        deserializer.deserialize_struct("Conversion", CONVERSION_FIELDS, ConversionVisitor)
+    }
+}
+
+/// `#[serde(with = "rust_iso4217::serde::numeric")]`: the ISO number as an
+/// integer. Codes without a number (`-1`) fail to serialize.
+pub mod numeric {
+    use core::fmt;
+
+    use ::serde::de::{self, Deserializer, Visitor};
+    use ::serde::ser::{Error, Serializer};
+
+    use crate::CurrencyCode;
+
+    pub fn serialize<S: Serializer>(code: &CurrencyCode, serializer: S) -> Result<S::Ok, S::Error> {
+        match u16::try_from(code.numeric) {
+            Ok(numeric) => serializer.serialize_u16(numeric),
+            Err(_) => Err(S::Error::custom(format_args!(
+                "{} has no ISO 4217 numeric code",
+                code.code
+            ))),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<CurrencyCode, D::Error> {
+        struct NumericVisitor;
+
+        impl Visitor<'_> for NumericVisitor {
+            type Value = CurrencyCode;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("an ISO 4217 numeric code")
+            }
+
+            fn visit_i64<E: de::Error>(self, v: i64) -> Result<CurrencyCode, E> {
+                let numeric = i32::try_from(v).unwrap_or(i32::MAX);
+                CurrencyCode::try_from(numeric).map_err(E::custom)
+            }
+
+            fn visit_u64<E: de::Error>(self, v: u64) -> Result<CurrencyCode, E> {
+                let numeric = i32::try_from(v).unwrap_or(i32::MAX);
+                CurrencyCode::try_from(numeric).map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_u16(NumericVisitor)
+    }
+}
+
+/// `#[serde(with = "rust_iso4217::serde::numeric_str")]`: the ISO number as
+/// a zero-padded 3 digit string, e.g. `"008"`.
+pub mod numeric_str {
+    use core::fmt;
+
+    use ::serde::de::{self, Deserializer, Visitor};
+    use ::serde::ser::{Error, Serializer};
+
+    use crate::{lookup, CurrencyCode, ParseCurrencyError};
+
+    pub fn serialize<S: Serializer>(code: &CurrencyCode, serializer: S) -> Result<S::Ok, S::Error> {
+        match u16::try_from(code.numeric) {
+            Ok(numeric) if numeric <= 999 => {
+                let digits = [
+                    b'0' + (numeric / 100) as u8,
+                    b'0' + (numeric / 10 % 10) as u8,
+                    b'0' + (numeric % 10) as u8,
+                ];
+                // Three ASCII digits are valid UTF-8.
+                serializer.serialize_str(core::str::from_utf8(&digits).unwrap_or_default())
+            }
+            _ => Err(S::Error::custom(format_args!(
+                "{} has no ISO 4217 numeric code",
+                code.code
+            ))),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<CurrencyCode, D::Error> {
+        struct NumericStrVisitor;
+
+        impl Visitor<'_> for NumericStrVisitor {
+            type Value = CurrencyCode;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a 3 digit ISO 4217 numeric code")
+            }
+
+            fn visit_str<E: de::Error>(self, v: &str) -> Result<CurrencyCode, E> {
+                if !v.is_ascii() {
+                    return Err(E::custom(ParseCurrencyError::NonAscii));
+                }
+                if v.len() != 3 {
+                    return Err(E::custom(ParseCurrencyError::WrongLength(v.len())));
+                }
+                match lookup::numeric_digits(v.as_bytes()) {
+                    Some(numeric) => CurrencyCode::try_from(numeric).map_err(E::custom),
+                    None => Err(E::invalid_value(de::Unexpected::Str(v), &self)),
+                }
+            }
+        }
+
+        deserializer.deserialize_str(NumericStrVisitor)
+    }
+}
+
+/// `#[serde(with = "rust_iso4217::serde::object")]`: every field of the
+/// struct. Deserialization looks the entry up by `code` and rejects objects
+/// whose other fields disagree with the table.
+pub mod object {
+    use core::fmt;
+
+    use ::serde::de::{
+        self, DeserializeSeed, Deserializer, IgnoredAny, MapAccess, SeqAccess, Visitor,
+    };
+    use ::serde::ser::{SerializeStruct, Serializer};
+
+    use crate::{Currency, CurrencyCode, CurrencySet};
+
+    const FIELDS: &[&str] = &["name", "code", "numeric", "unit", "code_type", "countries"];
+
+    pub fn serialize<S: Serializer>(code: &CurrencyCode, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut object = serializer.serialize_struct("CurrencyCode", FIELDS.len())?;
+        object.serialize_field("name", code.name)?;
+        object.serialize_field("code", code.code)?;
+        object.serialize_field("numeric", &code.numeric)?;
+        object.serialize_field("unit", &code.unit)?;
+        object.serialize_field("code_type", code.code_type.as_str())?;
+        object.serialize_field("countries", code.countries)?;
+        object.end()
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<CurrencyCode, D::Error> {
+        deserializer.deserialize_struct("CurrencyCode", FIELDS, ObjectVisitor)
+    }
+
+    enum Field {
+        Name,
+        Code,
+        Numeric,
+        Unit,
+        CodeType,
+        Countries,
+        Other,
+    }
+
+    impl<'de> de::Deserialize<'de> for Field {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            struct FieldVisitor;
+
+            impl Visitor<'_> for FieldVisitor {
+                type Value = Field;
+
+                fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    f.write_str("a CurrencyCode field name")
+                }
+
+                fn visit_str<E: de::Error>(self, v: &str) -> Result<Field, E> {
+                    Ok(match v {
+                        "name" => Field::Name,
+                        "code" => Field::Code,
+                        "numeric" => Field::Numeric,
+                        "unit" => Field::Unit,
+                        "code_type" => Field::CodeType,
+                        "countries" => Field::Countries,
+                        _ => Field::Other,
+                    })
+                }
+            }
+
+            deserializer.deserialize_identifier(FieldVisitor)
+        }
+    }
+
+    /// Reads a string as the currencies it is a valid value for, so fields
+    /// met before `code` are checked without allocating.
+    struct Matching<F>(F);
+
+    impl<'de, F: Fn(Currency, &str) -> bool> DeserializeSeed<'de> for Matching<F> {
+        type Value = CurrencySet;
+
+        fn deserialize<D: Deserializer<'de>>(
+            self,
+            deserializer: D,
+        ) -> Result<CurrencySet, D::Error> {
+            deserializer.deserialize_str(self)
+        }
+    }
+
+    impl<F: Fn(Currency, &str) -> bool> Visitor<'_> for Matching<F> {
+        type Value = CurrencySet;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("a string")
+        }
+
+        fn visit_str<E: de::Error>(self, v: &str) -> Result<CurrencySet, E> {
+            Ok(Currency::iter().filter(|c| (self.0)(*c, v)).collect())
+        }
+    }
+
+    /// The `countries` list, read as the currencies it matches.
+    struct Countries;
+
+    impl<'de> DeserializeSeed<'de> for Countries {
+        type Value = CurrencySet;
+
+        fn deserialize<D: Deserializer<'de>>(
+            self,
+            deserializer: D,
+        ) -> Result<CurrencySet, D::Error> {
+            deserializer.deserialize_seq(self)
+        }
+    }
+
+    impl<'de> Visitor<'de> for Countries {
+        type Value = CurrencySet;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("a list of country codes")
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<CurrencySet, A::Error> {
+            let mut matching = CurrencySet::all();
+            let mut len = 0;
+            loop {
+                let at = len;
+                let seed = Matching(move |c: Currency, v: &str| c.countries().get(at) == Some(&v));
+                let Some(set) = seq.next_element_seed(seed)? else {
+                    break;
+                };
+                matching = matching & set;
+                len += 1;
+            }
+            Ok(matching
+                .iter()
+                .filter(|c| c.countries().len() == len)
+                .collect())
+        }
+    }
+
+    struct ObjectVisitor;
+
+    impl<'de> Visitor<'de> for ObjectVisitor {
+        type Value = CurrencyCode;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("a CurrencyCode object")
+        }
+
+        fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<CurrencyCode, A::Error> {
+            let mut currency: Option<Currency> = None;
+            let mut numeric: Option<i32> = None;
+            let mut unit: Option<i32> = None;
+            let mut name: Option<CurrencySet> = None;
+            let mut code_type: Option<CurrencySet> = None;
+            let mut countries: Option<CurrencySet> = None;
+            while let Some(field) = map.next_key()? {
+                match field {
+                    Field::Name => {
+                        name = Some(
+                            map.next_value_seed(Matching(|c: Currency, v: &str| c.name() == v))?,
+                        )
+                    }
+                    Field::CodeType => {
+                        code_type =
+                            Some(map.next_value_seed(Matching(|c: Currency, v: &str| {
+                                c.code_type().as_str() == v
+                            }))?)
+                    }
+                    Field::Countries => countries = Some(map.next_value_seed(Countries)?),
+                    Field::Code => currency = Some(map.next_value()?),
+                    Field::Numeric => numeric = Some(map.next_value()?),
+                    Field::Unit => unit = Some(map.next_value()?),
+                    Field::Other => {
+                        map.next_value::<IgnoredAny>()?;
+                    }
+                }
+            }
+            let currency = currency.ok_or_else(|| de::Error::missing_field("code"))?;
+            let code = *currency.currency_code();
+            for (field, matching) in [
+                ("name", name),
+                ("code_type", code_type),
+                ("countries", countries),
+            ] {
+                if matching.is_some_and(|matching| !matching.contains(currency)) {
+                    return Err(de::Error::custom(format_args!(
+                        "{} does not match {}",
+                        field, code.code
+                    )));
+                }
+            }
+            if numeric.is_some_and(|n| n != code.numeric) {
+                return Err(de::Error::custom(format_args!(
+                    "numeric does not match {}",
+                    code.code
+                )));
+            }
+            if unit.is_some_and(|u| u != code.unit) {
+                return Err(de::Error::custom(format_args!(
+                    "unit does not match {}",
+                    code.code
+                )));
+            }
+            Ok(code)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Currency, CurrencyMap, CurrencySet};
+
+    #[test]
+    fn test_collections_round_trip() {
+        let set = CurrencySet::new().with(Currency::EUR).with(Currency::USD);
+        let json = serde_json::to_string(&set).unwrap();
+        assert_eq!(r#"["EUR","USD"]"#, json);
+        assert_eq!(set, serde_json::from_str(&json).unwrap());
+
+        let map: CurrencyMap<i64> = [(Currency::JPY, 5), (Currency::AFN, -1)]
+            .into_iter()
+            .collect();
+        let json = serde_json::to_string(&map).unwrap();
+        assert_eq!(r#"{"AFN":-1,"JPY":5}"#, json);
+        assert_eq!(map, serde_json::from_str(&json).unwrap());
+
+        let err = serde_json::from_str::<CurrencySet>(r#"["UDS"]"#).unwrap_err();
+        assert!(err.to_string().contains("did you mean USD"), "{}", err);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_conversion_round_trip() {
+        use crate::{Conversion, Money, OfflineRates, RateFormat, RoundingMode, Triangulator};
+
+        let mut rates = OfflineRates::new().with_name("ECB");
+        rates
+            .load(
+                "2025-10-17,EUR,USD,1.1681\n2025-10-17,EUR,JPY,175.53\n",
+                RateFormat::Csv,
+            )
+            .unwrap();
+        let usd = Money::from_decimal_str("100.00", crate::USD).unwrap();
+        let conversion = Triangulator::new(&rates)
+            .with_inverse_scale(6)
+            .convert(
+                usd,
+                crate::JPY,
+                "2025-10-17".parse().ok(),
+                RoundingMode::HalfEven,
+            )
+            .unwrap();
+        let json = serde_json::to_string(&conversion).unwrap();
+        assert_eq!(
+            concat!(
+                r#"{"input":"100.00 USD","output":"15027 JPY","#,
+                r#""rate":{"base":"USD","quote":"JPY","rate":"150.26965323","timestamp":1760659200},"#,
+                r#""legs":[{"base":"USD","quote":"EUR","rate":"0.856091","timestamp":1760659200},"#,
+                r#"{"base":"EUR","quote":"JPY","rate":"175.53","timestamp":1760659200}],"#,
+                r#""provider":"ECB","as_of":"2025-10-17","mode":"half_even","remainder":"-0.0346770000"}"#
+            ),
+            json
+        );
+        let restored: Conversion = serde_json::from_str(&json).unwrap();
+        assert_eq!(conversion, restored);
+        assert_eq!(Ok(restored.clone()), restored.replay());
+
+        let bad = json.replace("half_even", "bankers");
+        let err = serde_json::from_str::<Conversion>(&bad).unwrap_err();
+        assert!(
+            err.to_string().contains("unknown rounding mode bankers"),
+            "{}",
+            err
+        );
+        let bad = json.replace(r#""rate":"175.53""#, r#""rate":"-1""#);
+        let err = serde_json::from_str::<Conversion>(&bad).unwrap_err();
+        assert!(err.to_string().contains("must be positive"), "{}", err);
+        let err = serde_json::from_str::<Money>(r#""1.234 USD""#).unwrap_err();
+        assert!(err.to_string().contains("more decimals"), "{}", err);
+    }
+
+    #[test]
+    fn test_adapters() {
+        use crate::CurrencyCode;
+        use ::serde::{Deserialize, Serialize};
+
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Row {
+            alpha: CurrencyCode,
+            #[serde(with = "crate::serde::numeric")]
+            numeric: CurrencyCode,
+            #[serde(with = "crate::serde::numeric_str")]
+            padded: CurrencyCode,
+            #[serde(with = "crate::serde::object")]
+            object: CurrencyCode,
+        }
+
+        let row = Row {
+            alpha: crate::CHF,
+            numeric: crate::ALL,
+            padded: crate::ALL,
+            object: crate::KWD,
+        };
+        let json = serde_json::to_string(&row).unwrap();
+        assert_eq!(
+            r#"{"alpha":"CHF","numeric":8,"padded":"008","object":{"name":"Kuwaiti Dinar","code":"KWD","numeric":414,"unit":3,"code_type":"currency","countries":["KWT"]}}"#,
+            json
+        );
+        assert_eq!(row, serde_json::from_str(&json).unwrap());
+
+        let bad = json.replace(r#""numeric":8"#, r#""numeric":1"#);
+        let err = serde_json::from_str::<Row>(&bad).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("unknown ISO 4217 numeric code 001"),
+            "{}",
+            err
+        );
+        let bad = json.replace(r#""unit":3"#, r#""unit":2"#);
+        let err = serde_json::from_str::<Row>(&bad).unwrap_err();
+        assert!(
+            err.to_string().contains("unit does not match KWD"),
+            "{}",
+            err
+        );
+        for (from, to, field) in [
+            ("Kuwaiti Dinar", "Bahraini Dinar", "name"),
+            (r#""currency","#, r#""funds","#, "code_type"),
+            (r#"["KWT"]"#, r#"["KWT","BHR"]"#, "countries"),
+            (r#"["KWT"]"#, r#"[]"#, "countries"),
+        ] {
+            let err = serde_json::from_str::<Row>(&json.replace(from, to)).unwrap_err();
+            let expected = format!("{} does not match KWD", field);
+            assert!(err.to_string().contains(&expected), "{}", err);
+        }
+        // Escaped strings cannot be borrowed, and still match.
+        let escaped = json.replace("Kuwaiti", r"Kuwa\u0069ti");
+        assert_eq!(row, serde_json::from_str(&escaped).unwrap());
+        let err = serde_json::to_string(&Row {
+            numeric: crate::XFU,
+            ..row
+        })
+        .unwrap_err();
+        assert!(
+            err.to_string().contains("XFU has no ISO 4217 numeric code"),
+            "{}",
+            err
+        );
+    }
+}
@@ -0,0 +1,354 @@
+use alloc::collections::BTreeMap;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use crate::loader::{self, LoadIssue, RateFormat};
+use crate::{
+    Currency, CurrencyCode, CurrencyMap, CurrencySet, Date, Decimal, ExchangeRate, LoadError,
+    ParseCurrencyError, RateProvider, RoundingMode,
+};
+
+/// A `base/quote` currency pair.
+type Pair = (Currency, Currency);
+
+/// Published rates keyed by currency pair and date, answering "the rate in
+/// effect on a date".
+///
+/// A rate stays in effect after its date until a newer one is published, but
+/// only for as many days as the staleness limit of both currencies allows.
+/// Returned rates carry the Unix timestamp of midnight UTC on the date they
+/// were published.
+/// #Sample
+/// ```
+/// use rust_iso4217::{Date, RateFormat, RateSeries};
+///
+/// let mut series = RateSeries::new().with_max_age(3);
+/// series
+///     .load("2025-09-26,EUR,USD,1.1665\n2025-09-30,EUR,USD,1.1741\n", RateFormat::Csv)
+///     .unwrap();
+/// // Saturday 27 September falls back to Friday's rate.
+/// let saturday = Date::new(2025, 9, 27).unwrap();
+/// let rate = series.as_of(rust_iso4217::EUR, rust_iso4217::USD, saturday).unwrap();
+/// assert_eq!("EUR/USD 1.1665", rate.to_string());
+/// // On Monday, Friday's rate is too old for a two-day USD limit.
+/// let monday = Date::new(2025, 9, 29).unwrap();
+/// let series = series.with_currency_max_age(rust_iso4217::USD, 2);
+/// assert!(series.as_of(rust_iso4217::EUR, rust_iso4217::USD, monday).is_none());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RateSeries {
+    rates: BTreeMap<(Currency, Currency), BTreeMap<Date, Decimal>>,
+    issues: Vec<LoadIssue>,
+    max_age: Option<u32>,
+    currency_max_age: CurrencyMap<u32>,
+    name: String,
+}
+
+impl RateSeries {
+    /// Returns an empty series whose rates never go stale.
+    pub fn new() -> RateSeries {
+        RateSeries::default()
+    }
+
+    /// Sets the name reported by [`RateProvider::name`], e.g. `"ECB"`.
+    pub fn with_name(self, name: &str) -> RateSeries {
+        RateSeries {
+            name: name.to_string(),
+            ..self
+        }
+    }
+
+    /// Days a rate stays in effect after its date for every currency without
+    /// a limit of its own.
+    pub fn with_max_age(self, days: u32) -> RateSeries {
+        RateSeries {
+            max_age: Some(days),
+            ..self
+        }
+    }
+
+    /// Days a rate involving `currency` stays in effect after its date.
+    ///
+    /// A pair uses the stricter limit of its two currencies.
+    pub fn with_currency_max_age(mut self, currency: CurrencyCode, days: u32) -> RateSeries {
+        if let Some(currency) = currency.currency() {
+            self.currency_max_age.insert(currency, days);
+        }
+        self
+    }
+
+    /// Loads every rate in `text`, returning how many were read.
+    ///
+    /// On error nothing is loaded, issues included. Rates already present for
+    /// the same pair and date are replaced.
+    pub fn load(&mut self, text: &str, format: RateFormat) -> Result<usize, LoadError> {
+        let (records, issues) = loader::parse(text, format, &self.issues)?;
+        self.issues.extend(issues);
+        for record in &records {
+            self.rates
+                .entry((record.base, record.quote))
+                .or_default()
+                .insert(record.date, record.rate);
+        }
+        Ok(records.len())
+    }
+
+    /// Reads and loads the file at `path`.
+    #[cfg(feature = "std")]
+    pub fn load_file(
+        &mut self,
+        path: impl AsRef<std::path::Path>,
+        format: RateFormat,
+    ) -> Result<usize, LoadError> {
+        let text = std::fs::read_to_string(path)?;
+        self.load(&text, format)
+    }
+
+    /// Adds `rate` as published on `date`.
+    pub fn insert(&mut self, date: Date, rate: ExchangeRate) -> Result<(), ParseCurrencyError> {
+        let pair = (
+            Currency::try_from(rate.base())?,
+            Currency::try_from(rate.quote())?,
+        );
+        self.rates
+            .entry(pair)
+            .or_default()
+            .insert(date, rate.rate());
+        Ok(())
+    }
+
+    /// Unknown and historic codes met while loading, in order.
+    pub fn issues(&self) -> &[LoadIssue] {
+        &self.issues
+    }
+
+    /// Number of rates held, counting each pair and date once.
+    pub fn len(&self) -> usize {
+        self.rates.values().map(BTreeMap::len).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rates.is_empty()
+    }
+
+    /// The rate published exactly on `date`.
+    pub fn get(&self, base: CurrencyCode, quote: CurrencyCode, date: Date) -> Option<ExchangeRate> {
+        let (pair, dates) = self.dates(base, quote)?;
+        Some(exchange_rate(pair, date, *dates.get(&date)?))
+    }
+
+    /// The most recent rate.
+    pub fn latest(&self, base: CurrencyCode, quote: CurrencyCode) -> Option<ExchangeRate> {
+        let (pair, dates) = self.dates(base, quote)?;
+        let (date, rate) = dates.last_key_value()?;
+        Some(exchange_rate(pair, *date, *rate))
+    }
+
+    /// The last rate published on or before `date`, unless it is stale.
+    pub fn as_of(
+        &self,
+        base: CurrencyCode,
+        quote: CurrencyCode,
+        date: Date,
+    ) -> Option<ExchangeRate> {
+        let (pair, dates) = self.dates(base, quote)?;
+        let (published, rate) = dates.range(..=date).next_back()?;
+        self.fresh(pair, *published, date)
+            .then(|| exchange_rate(pair, *published, *rate))
+    }
+
+    /// Rates published from `from` to `to` inclusive, oldest first.
+    pub fn range(
+        &self,
+        base: CurrencyCode,
+        quote: CurrencyCode,
+        from: Date,
+        to: Date,
+    ) -> impl Iterator<Item = ExchangeRate> + '_ {
+        self.dates(base, quote)
+            .filter(|_| from <= to)
+            .into_iter()
+            .flat_map(move |(pair, dates)| {
+                dates
+                    .range(from..=to)
+                    .map(move |(date, rate)| exchange_rate(pair, *date, *rate))
+            })
+    }
+
+    /// The rate on `date` interpolated linearly between the closest
+    /// publications on either side, rounded to `scale` decimals.
+    ///
+    /// A rate published on `date` is returned as is. Both neighbours must be
+    /// within the staleness limit of `date`. The result carries the
+    /// timestamp of `date`.
+    pub fn interpolate(
+        &self,
+        base: CurrencyCode,
+        quote: CurrencyCode,
+        date: Date,
+        scale: u32,
+        mode: RoundingMode,
+    ) -> Option<ExchangeRate> {
+        let (pair, dates) = self.dates(base, quote)?;
+        let (before, low) = dates.range(..=date).next_back()?;
+        if *before == date {
+            return Some(exchange_rate(pair, date, *low));
+        }
+        let (after, high) = dates.range(date..).next()?;
+        if !self.fresh(pair, *before, date) || !self.fresh(pair, date, *after) {
+            return None;
+        }
+        // (low * span + (high - low) * elapsed) / span, rounded once.
+        let days = |from: &Date, to: &Date| {
+            Decimal::new((to.days_since_epoch() - from.days_since_epoch()) as i128, 0)
+        };
+        let (elapsed, span) = (days(before, &date), days(before, after));
+        let rate = low
+            .checked_mul(span)?
+            .checked_add(high.checked_sub(*low)?.checked_mul(elapsed)?)?
+            .checked_div(span, scale, mode)?;
+        Some(exchange_rate(pair, date, rate))
+    }
+
+    fn dates(
+        &self,
+        base: CurrencyCode,
+        quote: CurrencyCode,
+    ) -> Option<(Pair, &BTreeMap<Date, Decimal>)> {
+        let pair = (base.currency()?, quote.currency()?);
+        Some((pair, self.rates.get(&pair)?))
+    }
+
+    /// Whether a rate published on `published` may still be used on `date`.
+    fn fresh(&self, pair: Pair, published: Date, date: Date) -> bool {
+        let limit = |currency| {
+            self.currency_max_age
+                .get(currency)
+                .copied()
+                .or(self.max_age)
+        };
+        let age = date.days_since_epoch() - published.days_since_epoch();
+        match (limit(pair.0), limit(pair.1)) {
+            (Some(a), Some(b)) => age <= a.min(b) as i64,
+            (Some(limit), None) | (None, Some(limit)) => age <= limit as i64,
+            (None, None) => true,
+        }
+    }
+}
+
+fn exchange_rate(pair: Pair, date: Date, rate: Decimal) -> ExchangeRate {
+    // Rates are checked to be positive when loaded or inserted.
+    ExchangeRate::new(pair.0.into(), pair.1.into(), rate)
+        .unwrap_or_else(|_| unreachable!())
+        .with_timestamp(date.unix_timestamp())
+}
+
+/// `rate_on` answers with the rate in effect, see [`RateSeries::as_of`].
+impl RateProvider for RateSeries {
+    fn rate(&self, base: CurrencyCode, quote: CurrencyCode) -> Option<ExchangeRate> {
+        self.latest(base, quote)
+    }
+
+    fn rate_on(&self, base: CurrencyCode, quote: CurrencyCode, date: Date) -> Option<ExchangeRate> {
+        self.as_of(base, quote, date)
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn quotes(&self, base: CurrencyCode) -> CurrencySet {
+        self.rates
+            .keys()
+            .filter(|(b, _)| base.currency() == Some(*b))
+            .map(|(_, quote)| *quote)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Date, Decimal, RateFormat, RateProvider, RateSeries, RoundingMode};
+
+    fn date(s: &str) -> Date {
+        s.parse().unwrap()
+    }
+
+    fn series() -> RateSeries {
+        let mut series = RateSeries::new();
+        series
+            .load(
+                "Date,USD,JPY,\n2025-10-01,1.1741,173.76,\n2025-10-03,1.1717,N/A,\n2025-10-06,1.1697,175.50,\n",
+                RateFormat::EcbCsv,
+            )
+            .unwrap();
+        series
+    }
+
+    #[test]
+    fn test_as_of() {
+        let series = series();
+        let eur_usd = |series: &RateSeries, day| {
+            series
+                .as_of(crate::EUR, crate::USD, date(day))
+                .map(|rate| rate.rate().to_string())
+        };
+        assert_eq!(None, eur_usd(&series, "2025-09-30"));
+        assert_eq!(Some("1.1741".into()), eur_usd(&series, "2025-10-02"));
+        assert_eq!(Some("1.1717".into()), eur_usd(&series, "2025-10-05"));
+        assert_eq!(Some("1.1697".into()), eur_usd(&series, "2030-01-01"));
+        let rate = series
+            .as_of(crate::EUR, crate::USD, date("2025-10-05"))
+            .unwrap();
+        assert_eq!(Some(date("2025-10-03").unix_timestamp()), rate.timestamp());
+
+        let series = series.with_max_age(5).with_currency_max_age(crate::JPY, 1);
+        assert_eq!(None, eur_usd(&series, "2025-10-12"));
+        assert_eq!(Some("1.1697".into()), eur_usd(&series, "2025-10-11"));
+        // The JPY limit is stricter than the default.
+        let eur_jpy = |day| series.rate_on(crate::EUR, crate::JPY, date(day));
+        assert!(eur_jpy("2025-10-02").is_some());
+        assert!(eur_jpy("2025-10-03").is_none());
+        assert_eq!(None, series.get(crate::EUR, crate::JPY, date("2025-10-03")));
+    }
+
+    #[test]
+    fn test_range_and_interpolation() {
+        let series = series();
+        let rates: Vec<_> = series
+            .range(
+                crate::EUR,
+                crate::USD,
+                date("2025-10-02"),
+                date("2025-10-06"),
+            )
+            .map(|rate| rate.rate())
+            .collect();
+        assert_eq!(vec![Decimal::new(11717, 4), Decimal::new(11697, 4)], rates);
+        assert_eq!(
+            0,
+            series
+                .range(
+                    crate::EUR,
+                    crate::USD,
+                    date("2025-10-06"),
+                    date("2025-10-01")
+                )
+                .count()
+        );
+        let interpolate = |series: &RateSeries, day| {
+            series
+                .interpolate(crate::EUR, crate::JPY, date(day), 4, RoundingMode::HalfEven)
+                .map(|rate| rate.rate().to_string())
+        };
+        // 173.76 + (175.50 - 173.76) * 2 / 5
+        assert_eq!(Some("174.4560".into()), interpolate(&series, "2025-10-03"));
+        assert_eq!(Some("173.76".into()), interpolate(&series, "2025-10-01"));
+        assert_eq!(None, interpolate(&series, "2025-10-07"));
+        assert_eq!(Some("174.1080".into()), interpolate(&series, "2025-10-02"));
+        // 2025-10-06 is four days after 2025-10-02.
+        let strict = series.with_currency_max_age(crate::EUR, 3);
+        assert_eq!(None, interpolate(&strict, "2025-10-02"));
+        assert_eq!(Some("174.4560".into()), interpolate(&strict, "2025-10-03"));
+    }
+}
@@ -0,0 +1,356 @@
+use alloc::string::ToString;
+use alloc::vec::Vec;
+
+use crate::{
+    Conversion, Currency, CurrencyCode, CurrencyMap, CurrencySet, Date, Decimal, ExchangeRate,
+    Money, MoneyError, RateError, RateProvider, RoundingMode,
+};
+
+/// Decimals kept when a leg has to be inverted.
+const INVERSE_SCALE: u32 = 10;
+
+/// Finds rates for pairs a provider does not quote directly by chaining the
+/// pairs it does, e.g. `EUR/USD` and `USD/JPY` into `EUR/JPY`.
+///
+/// Paths with the fewest hops win. Among those, paths through fewer
+/// intermediates outside the vehicle currencies (`USD` and `EUR` unless
+/// configured) win, then paths with fewer inverted legs, since inverting a
+/// quote rounds it.
+/// #Sample
+/// ```
+/// use rust_iso4217::{OfflineRates, RateFormat, Triangulator};
+///
+/// let mut rates = OfflineRates::new();
+/// rates
+///     .load("2025-10-17,EUR,USD,1.1681\n2025-10-17,USD,JPY,150.25\n", RateFormat::Csv)
+///     .unwrap();
+/// let eur_jpy = Triangulator::new(&rates).find(rust_iso4217::EUR, rust_iso4217::JPY, None).unwrap();
+/// assert_eq!("EUR/JPY 175.507025", eur_jpy.rate.to_string());
+/// assert_eq!(vec!["EUR", "USD", "JPY"], eur_jpy.path().map(|c| c.code).collect::<Vec<_>>());
+/// ```
+#[derive(Debug, Clone)]
+pub struct Triangulator<P> {
+    provider: P,
+    vehicles: CurrencySet,
+    inverse_scale: u32,
+}
+
+/// A composed rate and the legs it was built from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Triangulated {
+    pub rate: ExchangeRate,
+    /// Rates chained in order, each oriented from the previous currency to
+    /// the next; inverted quotes appear already inverted.
+    pub legs: Vec<ExchangeRate>,
+}
+
+impl Triangulated {
+    /// Currencies visited, from the base to the quote.
+    pub fn path(&self) -> impl Iterator<Item = CurrencyCode> + '_ {
+        core::iter::once(self.rate.base()).chain(self.legs.iter().map(ExchangeRate::quote))
+    }
+}
+
+/// Cost of reaching a currency, compared in field order.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+struct Cost {
+    hops: u32,
+    off_vehicle: u32,
+    inverted: u32,
+}
+
+#[derive(Debug, Copy, Clone)]
+struct Step {
+    cost: Cost,
+    /// The leg arriving here; `None` at the start.
+    leg: Option<ExchangeRate>,
+}
+
+impl<P: RateProvider> Triangulator<P> {
+    pub fn new(provider: P) -> Triangulator<P> {
+        Triangulator {
+            provider,
+            vehicles: CurrencySet::new().with(Currency::USD).with(Currency::EUR),
+            inverse_scale: INVERSE_SCALE,
+        }
+    }
+
+    /// Currencies preferred as intermediates.
+    pub fn with_vehicles(self, vehicles: CurrencySet) -> Triangulator<P> {
+        Triangulator { vehicles, ..self }
+    }
+
+    /// Decimals kept when inverting a leg (10 by default).
+    pub fn with_inverse_scale(self, inverse_scale: u32) -> Triangulator<P> {
+        Triangulator {
+            inverse_scale,
+            ..self
+        }
+    }
+
+    pub fn provider(&self) -> &P {
+        &self.provider
+    }
+
+    /// Finds the best path from `base` to `quote` using the latest rates, or
+    /// the rates published on `date`.
+    pub fn find(
+        &self,
+        base: CurrencyCode,
+        quote: CurrencyCode,
+        date: Option<Date>,
+    ) -> Result<Triangulated, RateError> {
+        let no_path = RateError::NoPath(base.code, quote.code);
+        let (from, to) = match (base.currency(), quote.currency()) {
+            (Some(from), Some(to)) => (from, to),
+            _ => return Err(no_path),
+        };
+        if from == to {
+            return Ok(Triangulated {
+                rate: ExchangeRate::new(base, quote, Decimal::new(1, 0))?,
+                legs: Vec::new(),
+            });
+        }
+        // Direct quotes need no routing, even from providers that cannot
+        // enumerate their pairs.
+        if let Some((leg, _)) = self.leg(from, to, date) {
+            return Ok(Triangulated {
+                rate: leg,
+                legs: alloc::vec![leg],
+            });
+        }
+        let neighbours = self.neighbours();
+        let mut steps: CurrencyMap<Step> = CurrencyMap::new();
+        let mut done = CurrencySet::new();
+        steps.insert(
+            from,
+            Step {
+                cost: Cost {
+                    hops: 0,
+                    off_vehicle: 0,
+                    inverted: 0,
+                },
+                leg: None,
+            },
+        );
+        loop {
+            let (current, step) = steps
+                .iter()
+                .filter(|(currency, _)| !done.contains(*currency))
+                .min_by_key(|(_, step)| step.cost)
+                .map(|(currency, step)| (currency, *step))
+                .ok_or(no_path)?;
+            if current == to {
+                break;
+            }
+            done.insert(current);
+            let Some(next) = neighbours.get(current) else {
+                continue;
+            };
+            for next in (*next - done).iter() {
+                let Some((leg, inverted)) = self.leg(current, next, date) else {
+                    continue;
+                };
+                let passes_through = current != from && !self.vehicles.contains(current);
+                let cost = Cost {
+                    hops: step.cost.hops + 1,
+                    off_vehicle: step.cost.off_vehicle + passes_through as u32,
+                    inverted: step.cost.inverted + inverted as u32,
+                };
+                if steps.get(next).is_none_or(|known| cost < known.cost) {
+                    steps.insert(
+                        next,
+                        Step {
+                            cost,
+                            leg: Some(leg),
+                        },
+                    );
+                }
+            }
+        }
+        let mut legs = Vec::new();
+        let mut current = to;
+        while let Some(leg) = steps.get(current).and_then(|step| step.leg) {
+            legs.push(leg);
+            current = Currency::try_from(leg.base())?;
+        }
+        legs.reverse();
+        let mut rate = legs[0];
+        for leg in &legs[1..] {
+            rate = rate.cross(leg)?;
+        }
+        Ok(Triangulated { rate, legs })
+    }
+
+    /// Currencies quoted with each currency, in either direction.
+    fn neighbours(&self) -> CurrencyMap<CurrencySet> {
+        let mut neighbours: CurrencyMap<CurrencySet> = CurrencyMap::new();
+        for base in Currency::iter() {
+            for quote in self.provider.quotes(base.into()).iter() {
+                neighbours.entry_or_default(base).insert(quote);
+                neighbours.entry_or_default(quote).insert(base);
+            }
+        }
+        neighbours
+    }
+
+    /// Converts `money` into `quote` through the best path, recording how.
+    pub fn convert(
+        &self,
+        money: Money,
+        quote: CurrencyCode,
+        as_of: Option<Date>,
+        mode: RoundingMode,
+    ) -> Result<Conversion, MoneyError> {
+        let found = self.find(money.currency(), quote, as_of)?;
+        let conversion = Conversion::new(money, found.legs, mode)?;
+        Ok(Conversion {
+            rate: found.rate,
+            provider: self.provider.name().to_string(),
+            as_of,
+            ..conversion
+        })
+    }
+
+    /// The `from/to` rate, inverting a `to/from` quote when needed.
+    fn leg(
+        &self,
+        from: Currency,
+        to: Currency,
+        date: Option<Date>,
+    ) -> Option<(ExchangeRate, bool)> {
+        let fetch = |base: Currency, quote: Currency| match date {
+            Some(date) => self.provider.rate_on(base.into(), quote.into(), date),
+            None => self.provider.rate(base.into(), quote.into()),
+        };
+        if let Some(rate) = fetch(from, to) {
+            return Some((rate, false));
+        }
+        let inverse = fetch(to, from)?
+            .invert(self.inverse_scale, RoundingMode::HalfEven)
+            .ok()?;
+        Some((inverse, true))
+    }
+}
+
+/// Answers with triangulated rates, so a `Triangulator` can stand in for its
+/// provider.
+impl<P: RateProvider> RateProvider for Triangulator<P> {
+    fn rate(&self, base: CurrencyCode, quote: CurrencyCode) -> Option<ExchangeRate> {
+        Some(self.find(base, quote, None).ok()?.rate)
+    }
+
+    fn rate_on(&self, base: CurrencyCode, quote: CurrencyCode, date: Date) -> Option<ExchangeRate> {
+        Some(self.find(base, quote, Some(date)).ok()?.rate)
+    }
+
+    fn name(&self) -> &str {
+        self.provider.name()
+    }
+
+    fn quotes(&self, base: CurrencyCode) -> CurrencySet {
+        self.provider.quotes(base)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        Currency, CurrencyCode, CurrencySet, Date, Decimal, ExchangeRate, OfflineRates, RateError,
+        RateFormat, RateProvider, Triangulated, Triangulator,
+    };
+
+    fn rates(csv: &str) -> OfflineRates {
+        let mut rates = OfflineRates::new();
+        rates.load(csv, RateFormat::Csv).unwrap();
+        rates
+    }
+
+    fn path(found: &Triangulated) -> Vec<&'static str> {
+        found.path().map(|c| c.code).collect()
+    }
+
+    #[test]
+    fn test_vehicle_preference() {
+        // CHF and USD both link GBP to JPY; USD is a vehicle.
+        let rates = rates(
+            "2025-10-17,GBP,CHF,1.07\n2025-10-17,CHF,JPY,189.5\n\
+             2025-10-17,GBP,USD,1.34\n2025-10-17,USD,JPY,150.25\n\
+             2025-10-17,JPY,KRW,9.45\n",
+        );
+        let triangulator = Triangulator::new(&rates);
+        let found = triangulator.find(crate::GBP, crate::JPY, None).unwrap();
+        assert_eq!(vec!["GBP", "USD", "JPY"], path(&found));
+        assert_eq!("GBP/JPY 201.3350", found.rate.to_string());
+        let swiss = triangulator.with_vehicles(CurrencySet::new().with(Currency::CHF));
+        let found = swiss.find(crate::GBP, crate::KRW, None).unwrap();
+        assert_eq!(vec!["GBP", "CHF", "JPY", "KRW"], path(&found));
+        assert_eq!(3, found.legs.len());
+    }
+
+    /// Quotes one pair without listing it in `quotes`.
+    struct Direct;
+
+    impl RateProvider for Direct {
+        fn rate(&self, base: CurrencyCode, quote: CurrencyCode) -> Option<ExchangeRate> {
+            (base == crate::EUR && quote == crate::USD)
+                .then(|| ExchangeRate::new(base, quote, Decimal::new(11681, 4)).unwrap())
+        }
+
+        fn rate_on(
+            &self,
+            base: CurrencyCode,
+            quote: CurrencyCode,
+            _: Date,
+        ) -> Option<ExchangeRate> {
+            self.rate(base, quote)
+        }
+    }
+
+    #[test]
+    fn test_direct_without_quotes() {
+        let triangulator = Triangulator::new(Direct).with_inverse_scale(4);
+        let found = triangulator.find(crate::EUR, crate::USD, None).unwrap();
+        assert_eq!(vec!["EUR", "USD"], path(&found));
+        let day = Date::new(2025, 10, 17).unwrap();
+        let found = triangulator
+            .find(crate::USD, crate::EUR, Some(day))
+            .unwrap();
+        assert_eq!("USD/EUR 0.8561", found.rate.to_string());
+        assert_eq!(
+            Err(RateError::NoPath("EUR", "JPY")),
+            triangulator.find(crate::EUR, crate::JPY, None)
+        );
+    }
+
+    #[test]
+    fn test_inversion_and_dates() {
+        let rates = rates(
+            "2025-10-16,EUR,USD,1.25\n2025-10-17,EUR,USD,1.1681\n\
+             2025-10-17,EUR,JPY,175.53\n2025-10-16,USD,CAD,1.40\n",
+        );
+        let triangulator = Triangulator::new(&rates).with_inverse_scale(4);
+        // USD/EUR only exists inverted; a direct EUR/JPY quote follows.
+        let found = triangulator.find(crate::USD, crate::JPY, None).unwrap();
+        assert_eq!(vec!["USD", "EUR", "JPY"], path(&found));
+        assert_eq!("USD/EUR 0.8561", found.legs[0].to_string());
+        assert_eq!("USD/JPY 150.271233", found.rate.to_string());
+        // No EUR/JPY quote on the 16th.
+        let day = Date::new(2025, 10, 16).unwrap();
+        assert_eq!(
+            Err(RateError::NoPath("JPY", "CAD")),
+            triangulator.find(crate::JPY, crate::CAD, Some(day))
+        );
+        let found = triangulator
+            .find(crate::EUR, crate::CAD, Some(day))
+            .unwrap();
+        assert_eq!("EUR/CAD 1.7500", found.rate.to_string());
+        assert_eq!(Some(day.unix_timestamp()), found.rate.timestamp());
+        let same = triangulator.find(crate::CAD, crate::CAD, None).unwrap();
+        assert_eq!(vec!["CAD"], path(&same));
+        assert_eq!(
+            Err(RateError::NoPath("EUR", "GBP")),
+            triangulator.find(crate::EUR, crate::GBP, None)
+        );
+    }
+}